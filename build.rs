@@ -0,0 +1,128 @@
+extern crate pkg_config;
+
+use std::env;
+use std::process;
+
+/// Assimp releases whose struct layouts differ from their predecessor.
+/// A `assimp_X_Y` cfg is emitted for every entry not newer than the
+/// detected library, so the bindings can gate fields with `#[cfg(..)]`.
+const LAYOUT_VERSIONS: &[(u32, u32)] = &[(5, 1), (5, 2), (5, 3)];
+
+/// Version assumed when the library is configured by hand and
+/// `ASSIMP_VERSION` is not given.
+const DEFAULT_VERSION: &str = "5.2";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=ASSIMP_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=ASSIMP_LIB_NAME");
+    println!("cargo:rerun-if-env-changed=ASSIMP_VERSION");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_DLOPEN");
+
+    for &(major, minor) in LAYOUT_VERSIONS {
+        println!("cargo:rustc-check-cfg=cfg(assimp_{}_{})", major, minor);
+    }
+
+    let found = if env::var_os("CARGO_FEATURE_DLOPEN").is_some() {
+        Ok(runtime_version())
+    } else {
+        find_library()
+    };
+
+    let version = match found {
+        Ok(version) => version,
+        Err(msg) => {
+            eprintln!("error: could not find the assimp library\n\n{}\n", msg);
+            eprintln!(
+                "Either install assimp so that `pkg-config --libs assimp` succeeds, or set\n\
+                 ASSIMP_LIB_DIR to the directory containing the library and optionally\n\
+                 ASSIMP_LIB_NAME to its name (default: assimp) and ASSIMP_VERSION to its\n\
+                 version (default: {}).",
+                DEFAULT_VERSION
+            );
+            process::exit(1);
+        }
+    };
+
+    let (major, minor) = match parse_version(&version) {
+        Some(v) => v,
+        None => {
+            eprintln!("error: could not parse assimp version `{}`", version);
+            process::exit(1);
+        }
+    };
+
+    if major < 5 {
+        eprintln!(
+            "error: assimp {} is not supported, version 5.0 or newer is required",
+            version
+        );
+        process::exit(1);
+    }
+
+    for &(lmajor, lminor) in LAYOUT_VERSIONS {
+        if (major, minor) >= (lmajor, lminor) {
+            println!("cargo:rustc-cfg=assimp_{}_{}", lmajor, lminor);
+        }
+    }
+}
+
+/// Configures linking against libassimp and returns its version string.
+///
+/// pkg-config is consulted first, the `ASSIMP_LIB_DIR`/`ASSIMP_LIB_NAME`
+/// environment variables are used if it cannot find the library.
+fn find_library() -> Result<String, String> {
+    let pkg_err = match pkg_config::Config::new().probe("assimp") {
+        Ok(lib) => return Ok(env::var("ASSIMP_VERSION").unwrap_or(lib.version)),
+        Err(e) => e,
+    };
+
+    let dir = match env::var("ASSIMP_LIB_DIR") {
+        Ok(dir) => dir,
+        Err(_) => {
+            return Err(format!(
+                "pkg-config failed: {}\nASSIMP_LIB_DIR is not set.",
+                pkg_err
+            ))
+        }
+    };
+    let name = env::var("ASSIMP_LIB_NAME").unwrap_or_else(|_| "assimp".to_owned());
+    let version = env::var("ASSIMP_VERSION").unwrap_or_else(|_| {
+        println!(
+            "cargo:warning=ASSIMP_VERSION not set, assuming the assimp {} struct layout",
+            DEFAULT_VERSION
+        );
+        DEFAULT_VERSION.to_owned()
+    });
+
+    println!("cargo:rustc-link-search=native={}", dir);
+    println!("cargo:rustc-link-lib=dylib={}", name);
+    Ok(version)
+}
+
+/// Returns the version whose struct layout is used when the library is only
+/// loaded at runtime. Nothing is linked in that case.
+fn runtime_version() -> String {
+    if let Ok(version) = env::var("ASSIMP_VERSION") {
+        return version;
+    }
+    match pkg_config::Config::new()
+        .cargo_metadata(false)
+        .probe("assimp")
+    {
+        Ok(lib) => lib.version,
+        Err(_) => {
+            println!(
+                "cargo:warning=ASSIMP_VERSION not set, assuming the assimp {} struct layout",
+                DEFAULT_VERSION
+            );
+            DEFAULT_VERSION.to_owned()
+        }
+    }
+}
+
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    Some((major, minor))
+}
@@ -0,0 +1,153 @@
+use std::borrow::Cow;
+
+use data::*;
+use morph::MorphAnim;
+use util::{raw_ptr_slice, raw_slice};
+
+/// Ticks per second assumed when the file does not specify it.
+pub const DEFAULT_TICKS_PER_SECOND: f64 = 25.0;
+
+/// A borrowed, safe view of an `aiAnimation` clip.
+#[derive(Copy, Clone)]
+pub struct Animation<'a> {
+    anim: &'a aiAnimation,
+}
+
+impl<'a> Animation<'a> {
+    pub fn new(anim: &'a aiAnimation) -> Animation<'a> {
+        Animation { anim }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiAnimation {
+        self.anim
+    }
+
+    /// The name of the clip, usually empty for files with a single animation.
+    pub fn name(&self) -> Cow<'a, str> {
+        self.anim.mName.to_string_lossy()
+    }
+
+    /// Duration of the clip in ticks.
+    pub fn duration(&self) -> f64 {
+        self.anim.mDuration
+    }
+
+    /// Ticks per second, `DEFAULT_TICKS_PER_SECOND` if the file does not specify it.
+    pub fn ticks_per_second(&self) -> f64 {
+        if self.anim.mTicksPerSecond > 0.0 {
+            self.anim.mTicksPerSecond
+        } else {
+            DEFAULT_TICKS_PER_SECOND
+        }
+    }
+
+    /// Duration of the clip in seconds.
+    pub fn duration_seconds(&self) -> f64 {
+        self.duration() / self.ticks_per_second()
+    }
+
+    /// The node tracks, each one affecting a single node.
+    pub fn channels(&self) -> impl Iterator<Item = NodeTrack<'a>> + 'a {
+        unsafe { raw_ptr_slice(self.anim.mChannels, self.anim.mNumChannels) }
+            .iter()
+            .map(|&channel| NodeTrack::new(channel))
+    }
+
+    pub fn num_channels(&self) -> usize {
+        self.anim.mNumChannels as usize
+    }
+
+    /// The track animating the node with the given name.
+    pub fn channel(&self, node_name: &str) -> Option<NodeTrack<'a>> {
+        self.channels()
+            .find(|track| track.track.mNodeName.as_bytes() == node_name.as_bytes())
+    }
+
+    /// Vertex-based animation channels, keyed to the meshes' anim meshes.
+    pub fn mesh_channels(&self) -> &'a [&'a aiMeshAnim] {
+        unsafe { raw_ptr_slice(self.anim.mMeshChannels, self.anim.mNumMeshChannels) }
+    }
+
+    /// Safe views of the morph target weight channels.
+    pub fn morph_channels(&self) -> impl Iterator<Item = MorphAnim<'a>> + 'a {
+        self.morph_mesh_channels()
+            .iter()
+            .map(|&channel| MorphAnim::new(channel))
+    }
+
+    /// The morph target weights of the mesh with the given name.
+    pub fn morph_channel(&self, mesh_name: &str) -> Option<MorphAnim<'a>> {
+        self.morph_channels()
+            .find(|channel| channel.raw().mName.as_bytes() == mesh_name.as_bytes())
+    }
+
+    /// Morph target weight channels.
+    pub fn morph_mesh_channels(&self) -> &'a [&'a aiMeshMorphAnim] {
+        unsafe {
+            raw_ptr_slice(
+                self.anim.mMorphMeshChannels,
+                self.anim.mNumMorphMeshChannels,
+            )
+        }
+    }
+}
+
+impl<'a> From<&'a aiAnimation> for Animation<'a> {
+    fn from(anim: &'a aiAnimation) -> Animation<'a> {
+        Animation::new(anim)
+    }
+}
+
+/// A borrowed, safe view of an `aiNodeAnim`, the keyframes of a single node.
+///
+/// Key times are in ticks and sorted, the keys replace the node's
+/// transformation, scaling first, then rotation, then translation.
+#[derive(Copy, Clone)]
+pub struct NodeTrack<'a> {
+    track: &'a aiNodeAnim,
+}
+
+impl<'a> NodeTrack<'a> {
+    pub fn new(track: &'a aiNodeAnim) -> NodeTrack<'a> {
+        NodeTrack { track }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiNodeAnim {
+        self.track
+    }
+
+    /// The name of the animated node.
+    pub fn node_name(&self) -> Cow<'a, str> {
+        self.track.mNodeName.to_string_lossy()
+    }
+
+    pub fn position_keys(&self) -> &'a [aiVectorKey] {
+        unsafe { raw_slice(self.track.mPositionKeys, self.track.mNumPositionKeys) }
+    }
+
+    pub fn rotation_keys(&self) -> &'a [aiQuatKey] {
+        unsafe { raw_slice(self.track.mRotationKeys, self.track.mNumRotationKeys) }
+    }
+
+    pub fn scaling_keys(&self) -> &'a [aiVectorKey] {
+        unsafe { raw_slice(self.track.mScalingKeys, self.track.mNumScalingKeys) }
+    }
+
+    /// Behaviour before the first key, `Default` for unknown values.
+    pub fn pre_state(&self) -> aiAnimBehaviour {
+        aiAnimBehaviour::from_raw(self.track.mPreState).unwrap_or(aiAnimBehaviour::Default)
+    }
+
+    /// Behaviour after the last key, `Default` for unknown values.
+    pub fn post_state(&self) -> aiAnimBehaviour {
+        aiAnimBehaviour::from_raw(self.track.mPostState).unwrap_or(aiAnimBehaviour::Default)
+    }
+}
+
+impl<'a> From<&'a aiNodeAnim> for NodeTrack<'a> {
+    fn from(track: &'a aiNodeAnim) -> NodeTrack<'a> {
+        NodeTrack::new(track)
+    }
+}
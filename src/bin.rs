@@ -7,7 +7,7 @@ extern crate glui;
 extern crate glui_proc;
 extern crate rand;
 
-use assimp::{aiImportFile, aiPostProcessSteps, aiReleaseImport};
+use assimp::{aiPostProcessSteps, Scene};
 
 use glui::graphics::{DrawShaderSelector, RenderCommand, RenderSequence};
 use glui::mecs::*;
@@ -25,35 +25,24 @@ fn load_object() -> RenderSequence {
     let mut pts = vec![];
     let mut indices = vec![];
     let mut normals = vec![];
-    let ptr = aiImportFile(
+    let scene = Scene::import(
         "suzanne.obj",
         aiPostProcessSteps::Triangulate | aiPostProcessSteps::GenSmoothNormals,
-    );
-    unsafe {
-        let mesh_count = (*ptr).mNumMeshes as usize;
-        println!("Meshes: {}", mesh_count);
-
-        for j in 0..mesh_count {
-            let mesh = &*(*(*ptr).mMeshes.add(j));
-            let vertex_count = mesh.mNumVertices as usize;
-            let face_count = mesh.mNumFaces as usize;
-
-            println!("Vertices of mesh: {}", vertex_count);
-
-            for i in 0..vertex_count {
-                pts.push(*mesh.mVertices.add(i));
-                normals.push(*mesh.mNormals.add(i));
-            }
-
-            for i in 0..face_count {
-                let face = &*mesh.mFaces.add(i);
-                for k in 0..3 {
-                    indices.push(*face.mIndices.add(k));
-                }
-            }
+    )
+    .expect("failed to import suzanne.obj");
+    println!("Meshes: {}", scene.meshes().len());
+
+    for mesh in scene.mesh_views() {
+        println!("Vertices of mesh: {}", mesh.num_vertices());
+
+        let ind_base = pts.len() as u32;
+        pts.extend_from_slice(mesh.positions());
+        normals.extend_from_slice(mesh.normals());
+
+        for face in mesh.faces() {
+            indices.extend(face.iter().map(|&i| i + ind_base));
         }
     }
-    aiReleaseImport(ptr);
 
     let clr: Vec<Vec4> = normals
         .iter()
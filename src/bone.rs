@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use data::*;
+use node::Node;
+use util::raw_slice;
+
+/// A borrowed, safe view of an `aiBone`.
+#[derive(Copy, Clone)]
+pub struct Bone<'a> {
+    bone: &'a aiBone,
+}
+
+impl<'a> Bone<'a> {
+    pub fn new(bone: &'a aiBone) -> Bone<'a> {
+        Bone { bone }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiBone {
+        self.bone
+    }
+
+    /// The name of the bone, which is also the name of its node.
+    pub fn name(&self) -> Cow<'a, str> {
+        self.bone.mName.to_string_lossy()
+    }
+
+    /// The vertices influenced by the bone.
+    pub fn weights(&self) -> &'a [aiVertexWeight] {
+        unsafe { raw_slice(self.bone.mWeights, self.bone.mNumWeights) }
+    }
+
+    /// Transforms from mesh space to the bone's space in bind pose,
+    /// also known as the inverse bind matrix.
+    pub fn offset_matrix(&self) -> aiMatrix4x4 {
+        self.bone.mOffsetMatrix
+    }
+
+    /// The bone's node, only set when imported with `PopulateArmatureData`.
+    #[cfg(assimp_5_1)]
+    pub fn node(&self) -> Option<Node<'a>> {
+        unsafe { self.bone.mNode.as_ref() }.map(Node::new)
+    }
+
+    /// The bone's node, always `None` before assimp 5.1.
+    #[cfg(not(assimp_5_1))]
+    pub fn node(&self) -> Option<Node<'a>> {
+        None
+    }
+
+    /// The root node of the bone's armature, only set when imported with
+    /// `PopulateArmatureData`.
+    #[cfg(assimp_5_1)]
+    pub fn armature(&self) -> Option<Node<'a>> {
+        unsafe { self.bone.mArmature.as_ref() }.map(Node::new)
+    }
+
+    /// The root node of the bone's armature, always `None` before assimp 5.1.
+    #[cfg(not(assimp_5_1))]
+    pub fn armature(&self) -> Option<Node<'a>> {
+        None
+    }
+}
+
+impl<'a> From<&'a aiBone> for Bone<'a> {
+    fn from(bone: &'a aiBone) -> Bone<'a> {
+        Bone::new(bone)
+    }
+}
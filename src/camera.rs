@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+
+use data::*;
+use glui::tools::Mat4;
+use scene::Scene;
+use util::{cross, normalize};
+
+/// A borrowed, safe view of an `aiCamera`.
+///
+/// The camera's parameters are relative to the node with the same name,
+/// the matrix helpers combine them with that node's world transformation.
+#[derive(Copy, Clone)]
+pub struct Camera<'a> {
+    cam: &'a aiCamera,
+}
+
+impl<'a> Camera<'a> {
+    pub fn new(cam: &'a aiCamera) -> Camera<'a> {
+        Camera { cam }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiCamera {
+        self.cam
+    }
+
+    /// The name of the camera, which is also the name of its node.
+    pub fn name(&self) -> Cow<'a, str> {
+        self.cam.mName.to_string_lossy()
+    }
+
+    /// Position relative to the camera's node.
+    pub fn position(&self) -> aiVector3D {
+        self.cam.mPosition
+    }
+
+    /// Up vector relative to the camera's node.
+    pub fn up(&self) -> aiVector3D {
+        self.cam.mUp
+    }
+
+    /// Viewing direction relative to the camera's node.
+    pub fn look_at(&self) -> aiVector3D {
+        self.cam.mLookAt
+    }
+
+    /// Half of the horizontal field of view angle, in radians.
+    pub fn horizontal_fov(&self) -> f32 {
+        self.cam.mHorizontalFOV
+    }
+
+    pub fn clip_plane_near(&self) -> f32 {
+        self.cam.mClipPlaneNear
+    }
+
+    pub fn clip_plane_far(&self) -> f32 {
+        self.cam.mClipPlaneFar
+    }
+
+    /// Width to height ratio of the screen, `None` if the file does not define it.
+    pub fn aspect(&self) -> Option<f32> {
+        if self.cam.mAspect > 0.0 {
+            Some(self.cam.mAspect)
+        } else {
+            None
+        }
+    }
+
+    /// Half width of the view box for orthographic cameras, `None` for perspective ones.
+    #[cfg(assimp_5_1)]
+    pub fn orthographic_width(&self) -> Option<f32> {
+        if self.cam.mOrthographicWidth > 0.0 {
+            Some(self.cam.mOrthographicWidth)
+        } else {
+            None
+        }
+    }
+
+    /// Half width of the view box for orthographic cameras, `None` for perspective ones.
+    #[cfg(not(assimp_5_1))]
+    pub fn orthographic_width(&self) -> Option<f32> {
+        None
+    }
+
+    /// World transformation of the camera's node, identity if there is no such node.
+    pub fn world_transformation(&self, scene: &Scene) -> aiMatrix4x4 {
+        let name = self.cam.mName.to_string_lossy();
+        scene
+            .find_node(&name)
+            .map_or_else(aiMatrix4x4::identity, |node| node.world_transformation())
+    }
+
+    /// The world to view space transformation, placing the camera with its node.
+    ///
+    /// The view space is right-handed with the camera looking along -Z, as in OpenGL.
+    pub fn view_matrix(&self, scene: &Scene) -> Mat4 {
+        self.view_matrix_at(&self.world_transformation(scene))
+    }
+
+    /// The world to view space transformation for a camera node placed at `world`.
+    pub fn view_matrix_at(&self, world: &aiMatrix4x4) -> Mat4 {
+        self.view(world).into()
+    }
+
+    fn view(&self, world: &aiMatrix4x4) -> aiMatrix4x4 {
+        let eye = world.transform_point(self.cam.mPosition);
+        let forward = normalize(world.transform_vector(self.cam.mLookAt));
+        let right = normalize(cross(forward, world.transform_vector(self.cam.mUp)));
+        let up = cross(right, forward);
+
+        aiMatrix4x4::from_rows([
+            [right.x, right.y, right.z, -right.dot(eye)],
+            [up.x, up.y, up.z, -up.dot(eye)],
+            [-forward.x, -forward.y, -forward.z, forward.dot(eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// The view to clip space transformation, mapping depth to [-1, 1] as in OpenGL.
+    ///
+    /// `aspect` is used when the camera does not define its aspect ratio.
+    pub fn projection_matrix(&self, aspect: f32) -> Mat4 {
+        self.projection(aspect).into()
+    }
+
+    fn projection(&self, aspect: f32) -> aiMatrix4x4 {
+        let aspect = self.aspect().unwrap_or(aspect);
+        let near = self.cam.mClipPlaneNear;
+        let far = self.cam.mClipPlaneFar;
+
+        if let Some(half_width) = self.orthographic_width() {
+            let half_height = half_width / aspect;
+            return aiMatrix4x4::from_rows([
+                [1.0 / half_width, 0.0, 0.0, 0.0],
+                [0.0, 1.0 / half_height, 0.0, 0.0],
+                [0.0, 0.0, 2.0 / (near - far), (far + near) / (near - far)],
+                [0.0, 0.0, 0.0, 1.0],
+            ]);
+        }
+
+        let x_scale = 1.0 / self.cam.mHorizontalFOV.tan();
+        let y_scale = x_scale * aspect;
+        aiMatrix4x4::from_rows([
+            [x_scale, 0.0, 0.0, 0.0],
+            [0.0, y_scale, 0.0, 0.0],
+            [
+                0.0,
+                0.0,
+                (far + near) / (near - far),
+                2.0 * far * near / (near - far),
+            ],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+}
+
+impl<'a> From<&'a aiCamera> for Camera<'a> {
+    fn from(cam: &'a aiCamera) -> Camera<'a> {
+        Camera::new(cam)
+    }
+}
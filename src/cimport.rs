@@ -2,22 +2,21 @@
 #![allow(non_snake_case)]
 extern crate libc;
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_uint};
+use std::ptr;
+use std::sync::Mutex;
 
 use data::*;
+use error::Error;
+use filesystem::{FileIo, FileSystem};
 use glui::tools::mesh::{Mesh, MeshFace};
+use glui::tools::Vec2;
+use importer::{Importer, LogGuard, LogSink};
+use mesh::MeshView;
 use postprocess::aiPostProcessSteps;
-
-mod raw_assimp {
-    use aiScene;
-    use std::os::raw::{c_char, c_uint};
-
-    #[link(name = "c:/Projects/rust/assimp/assimp-vc141-mt")]
-    extern "C" {
-        pub fn aiImportFile(pFile: *const c_char, pFlags: c_uint) -> *const aiScene;
-        pub fn aiReleaseImport(pScene: *const aiScene);
-    }
-}
+use raw_assimp;
+use util::normalize;
 
 // --------------------------------------------------------------------------------
 /** Reads the given file and returns its content.
@@ -33,11 +32,194 @@ mod raw_assimp {
  * @param pFlags Optional post processing steps to be executed after
  *   a successful import. Provide a bitwise combination of the
  *   #aiPostProcessSteps flags.
- * @return Pointer to the imported data or NULL if the import failed.
+ * @return Pointer to the imported data or the error if the import failed.
+ *   Scenes with the AI_SCENE_FLAGS_INCOMPLETE flag set, like animation-only
+ *   files, are returned as well.
  */
-pub fn aiImportFile(pFile: &str, pFlags: aiPostProcessSteps) -> *const aiScene {
-    let cstr = CString::new(pFile).unwrap();
-    unsafe { raw_assimp::aiImportFile(cstr.as_ptr(), pFlags.into()) }
+pub fn aiImportFile(pFile: &str, pFlags: aiPostProcessSteps) -> Result<*const aiScene, Error> {
+    raw_assimp::check_loaded()?;
+    let cstr = CString::new(pFile)?;
+    check_import(unsafe { raw_assimp::aiImportFile(cstr.as_ptr(), pFlags.into()) })
+}
+
+// --------------------------------------------------------------------------------
+/** Reads the given file using user-defined I/O functions and returns
+ *   its content.
+ *
+ * If the call succeeds, the imported data is returned in an aiScene structure.
+ * The data is intended to be read-only, it stays property of the ASSIMP
+ * library and will be stable until aiReleaseImport() is called. After you're
+ * done with it, call aiReleaseImport() to free the resources associated with
+ * this file. If the import fails, the error is returned.
+ * @param pFile Path and filename of the file to be imported,
+ *   expected to be a null-terminated c-string. NULL is not a valid value.
+ * @param pFlags Optional post processing steps to be executed after
+ *   a successful import. Provide a bitwise combination of the
+ *   #aiPostProcessSteps flags.
+ * @param pFS The file system used to open the file and every file it references.
+ * @return Pointer to the imported data or the error if the import failed.
+ */
+pub fn aiImportFileEx(
+    pFile: &str,
+    pFlags: aiPostProcessSteps,
+    pFS: &dyn FileSystem,
+) -> Result<*const aiScene, Error> {
+    raw_assimp::check_loaded()?;
+    let cstr = CString::new(pFile)?;
+    let mut io = FileIo::new(pFS);
+    check_import(unsafe {
+        raw_assimp::aiImportFileEx(cstr.as_ptr(), pFlags.into(), io.as_mut_ptr())
+    })
+}
+
+// --------------------------------------------------------------------------------
+/** Same as #aiImportFileEx, but adds an extra parameter containing importer settings.
+ *
+ * @param pFS The file system to use, None to read from disk.
+ * @param pProps #aiPropertyStore instance containing import settings.
+ * @see aiImportFileEx
+ */
+pub fn aiImportFileExWithProperties(
+    pFile: &str,
+    pFlags: aiPostProcessSteps,
+    pFS: Option<&dyn FileSystem>,
+    pProps: &aiPropertyStore,
+) -> Result<*const aiScene, Error> {
+    raw_assimp::check_loaded()?;
+    let cstr = CString::new(pFile)?;
+    let mut io = pFS.map(FileIo::new);
+    let pFS = io.as_mut().map_or(ptr::null_mut(), |io| io.as_mut_ptr());
+    check_import(unsafe {
+        raw_assimp::aiImportFileExWithProperties(cstr.as_ptr(), pFlags.into(), pFS, pProps)
+    })
+}
+
+// --------------------------------------------------------------------------------
+/** Reads the given file from a given memory buffer,
+ *
+ * If the call succeeds, the contents of the file are returned as a pointer to an
+ * aiScene object. The returned data is intended to be read-only, the importer keeps
+ * ownership of the data and will destroy it upon destruction. If the import fails,
+ * the error is returned.
+ * @param pBuffer Buffer containing the file.
+ * @param pFlags Optional post processing steps to be executed after
+ *   a successful import. Provide a bitwise combination of the
+ *   #aiPostProcessSteps flags.
+ * @param pHint An additional hint to the library. If this is a non empty string,
+ *   the library looks for a loader to support the file extension specified by pHint
+ *   and passes the file to the first matching loader. If this loader is unable to
+ *   completely the request, the library continues and tries to determine the file
+ *   format on its own, a task that may or may not be successful.
+ * @return Pointer to the imported data or the error if the import failed.
+ * @note This is a straightforward way to decode models from memory
+ * buffers, but it doesn't handle model formats that spread their
+ * data across multiple files or even directories. Examples include
+ * OBJ or MD3, which outsource parts of their material info into
+ * external scripts. If you need full functionality, provide
+ * a custom IOSystem to make Assimp find these files and use
+ * the regular aiImportFileEx()/aiImportFileExWithProperties() API.
+ */
+pub fn aiImportFileFromMemory(
+    pBuffer: &[u8],
+    pFlags: aiPostProcessSteps,
+    pHint: &str,
+) -> Result<*const aiScene, Error> {
+    raw_assimp::check_loaded()?;
+    let length = buffer_length(pBuffer)?;
+    let hint = CString::new(pHint)?;
+    check_import(unsafe {
+        raw_assimp::aiImportFileFromMemory(
+            pBuffer.as_ptr() as *const c_char,
+            length,
+            pFlags.into(),
+            hint.as_ptr(),
+        )
+    })
+}
+
+// --------------------------------------------------------------------------------
+/** Same as #aiImportFileFromMemory, but adds an extra parameter
+ *  containing importer settings.
+ *
+ * @param pProps #aiPropertyStore instance containing import settings.
+ * @see aiImportFileFromMemory
+ */
+pub fn aiImportFileFromMemoryWithProperties(
+    pBuffer: &[u8],
+    pFlags: aiPostProcessSteps,
+    pHint: &str,
+    pProps: &aiPropertyStore,
+) -> Result<*const aiScene, Error> {
+    raw_assimp::check_loaded()?;
+    let length = buffer_length(pBuffer)?;
+    let hint = CString::new(pHint)?;
+    check_import(unsafe {
+        raw_assimp::aiImportFileFromMemoryWithProperties(
+            pBuffer.as_ptr() as *const c_char,
+            length,
+            pFlags.into(),
+            hint.as_ptr(),
+            pProps,
+        )
+    })
+}
+
+/// The length of a buffer passed to assimp, which takes 32 bit lengths.
+fn buffer_length(pBuffer: &[u8]) -> Result<c_uint, Error> {
+    if pBuffer.len() > c_uint::MAX as usize {
+        return Err(Error::BufferTooLarge(pBuffer.len()));
+    }
+    Ok(pBuffer.len() as c_uint)
+}
+
+/// Turns the result of an import function into a `Result`.
+pub(crate) fn check_import(pScene: *const aiScene) -> Result<*const aiScene, Error> {
+    if pScene.is_null() {
+        return Err(Error::from_assimp(aiGetErrorString()));
+    }
+    Ok(pScene)
+}
+
+// --------------------------------------------------------------------------------
+/** Apply post-processing to an already-imported scene.
+ *
+ * This is strictly equivalent to calling #aiImportFile()/#aiImportFileEx with the
+ * same flags. However, you can use this separate function to inspect the imported
+ * scene first to fine-tune your post-processing setup.
+ * @param pScene Scene to work on.
+ * @param pFlags Provide a bitwise combination of the #aiPostProcessSteps flags.
+ * @return A pointer to the post-processed data. Post processing is done in-place,
+ *   meaning this is still the same #aiScene which you passed for pScene. If
+ *   post-processing failed, the scene is released and Error::PostProcessing
+ *   is returned with the last error assimp logged on this thread.
+ *
+ * # Safety
+ * pScene must have been returned by one of the import functions, must not
+ * be released yet and must not be owned by a Scene, it is released on failure.
+ */
+pub unsafe fn aiApplyPostProcessing(
+    pScene: *const aiScene,
+    pFlags: aiPostProcessSteps,
+) -> Result<*const aiScene, Error> {
+    raw_assimp::check_loaded()?;
+    // the importer and its error string are released on failure, only the log keeps the reason
+    let mut message = None;
+    let pScene = {
+        let sink: LogSink = Mutex::new(Box::new(|msg: &str| {
+            if msg.starts_with("Error") {
+                message = Some(msg.split_once(": ").map_or(msg, |(_, m)| m).to_string());
+            }
+        }));
+        let _log = LogGuard::attach(&sink);
+        raw_assimp::aiApplyPostProcessing(pScene, pFlags.into())
+    };
+    if pScene.is_null() {
+        return Err(Error::PostProcessing {
+            steps: pFlags,
+            message: message.unwrap_or_else(|| "no error was logged".to_string()),
+        });
+    }
+    Ok(pScene)
 }
 
 // --------------------------------------------------------------------------------
@@ -52,121 +234,129 @@ pub fn aiReleaseImport(pScene: *const aiScene) {
     }
 }
 
-pub fn aiImportFileToMesh(file: &str) -> Option<Mesh> {
-    let mut pts = vec![];
-    let mut tpt = vec![];
-    let mut faces = vec![];
-    let mut normals = vec![];
-    let ptr = aiImportFile(
-        file,
-        aiPostProcessSteps::Triangulate
-            | aiPostProcessSteps::GenSmoothNormals
-            | aiPostProcessSteps::GenUVCoords
-            | aiPostProcessSteps::FlipUVs,
-    );
-    if ptr.is_null() {
-        return None;
+// --------------------------------------------------------------------------------
+/** Returns the error text of the last failed import process.
+ *
+ * @return A textual description of the error that occurred at the last
+ * import process. An empty string if there was no error.
+ */
+pub fn aiGetErrorString() -> String {
+    if raw_assimp::check_loaded().is_err() {
+        return String::new();
     }
     unsafe {
-        let mesh_count = (*ptr).mNumMeshes as usize;
-        // println!("Meshes: {}", mesh_count);
-
-        let mut ind_base = 0;
+        let ptr = raw_assimp::aiGetErrorString();
+        if ptr.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(ptr).to_string_lossy().into_owned()
+        }
+    }
+}
 
-        for j in 0..mesh_count {
-            let mesh = &*(*(*ptr).mMeshes.add(j));
-            let vertex_count = mesh.mNumVertices as usize;
-            let face_count = mesh.mNumFaces as usize;
+/// Imports a file with `Importer::default_steps` and merges its meshes.
+///
+/// The meshes are merged in the order of the scene's mesh list, in their own
+/// local space, see `Scene::to_world_mesh` to place them by the node hierarchy.
+/// Only triangles are kept, the lines and points of a mesh are dropped. The
+/// normals are `None` unless every mesh has normals.
+pub fn aiImportFileToMesh(file: &str) -> Result<Mesh, Error> {
+    let scene = Importer::new().read_file(file)?;
+    Ok(merge_meshes(scene.mesh_views().map(|mesh| (mesh, None))))
+}
 
-            // println!("Vertices of mesh: {}", vertex_count);
+/// Merges the triangles of meshes into one glui mesh, each mesh is transformed
+/// by its matrix if it has one.
+///
+/// Missing uvs are filled with zeros, the normals are `None` unless every mesh
+/// has normals.
+pub(crate) fn merge_meshes<'a, I>(meshes: I) -> Mesh
+where
+    I: IntoIterator<Item = (MeshView<'a>, Option<aiMatrix4x4>)>,
+{
+    let mut pts = vec![];
+    let mut tpt = vec![];
+    let mut faces = vec![];
+    let mut normals = vec![];
+    let mut has_normals = true;
+    let mut ind_base = 0;
 
-            for i in 0..vertex_count {
-                pts.push(*mesh.mVertices.add(i));
-                normals.push(*mesh.mNormals.add(i));
-                tpt.push((*mesh.mTextureCoords[0].add(i)).xy())
+    for (mesh, transform) in meshes {
+        match transform {
+            Some(m) => {
+                let normal_matrix = m.normal_matrix();
+                pts.extend(mesh.positions().iter().map(|&p| m.transform_point(p)));
+                normals.extend(
+                    mesh.normals()
+                        .iter()
+                        .map(|&n| normalize(normal_matrix.transform_vector(n))),
+                );
             }
-
-            for i in 0..face_count {
-                let face = &*mesh.mFaces.add(i);
-                let a = *face.mIndices.add(0) + ind_base;
-                let b = *face.mIndices.add(1) + ind_base;
-                let c = *face.mIndices.add(2) + ind_base;
-                faces.push(MeshFace::new(a, b, c));
+            None => {
+                pts.extend_from_slice(mesh.positions());
+                normals.extend_from_slice(mesh.normals());
             }
+        }
+        has_normals &= mesh.normals().len() == mesh.num_vertices();
 
-            ind_base += vertex_count as u32;
+        match mesh.uv_channel(0) {
+            Some(uvs) => tpt.extend(uvs.iter().map(|uv| uv.xy())),
+            None => tpt.extend((0..mesh.num_vertices()).map(|_| Vec2::new(0.0, 0.0))),
         }
+
+        for face in mesh.faces().filter(|face| face.len() == 3) {
+            faces.push(MeshFace::new(
+                face[0] + ind_base,
+                face[1] + ind_base,
+                face[2] + ind_base,
+            ));
+        }
+
+        ind_base += mesh.num_vertices() as u32;
     }
-    aiReleaseImport(ptr);
 
-    Some(Mesh {
+    Mesh {
         points: pts,
-        normals: Some(normals),
+        normals: if has_normals { Some(normals) } else { None },
         faces,
         uvcoords: Some(tpt),
-    })
-}
-
-pub fn aiImportFileToMeshes(file: &str) -> Option<Vec<Mesh>> {
-    let ptr = aiImportFile(
-        file,
-        aiPostProcessSteps::Triangulate
-            | aiPostProcessSteps::GenSmoothNormals
-            | aiPostProcessSteps::GenUVCoords,
-    );
-    if ptr.is_null() {
-        return None;
     }
-    let mut meshes;
-
-    unsafe {
-        let mesh_count = (*ptr).mNumMeshes as usize;
-        // println!("Meshes: {}", mesh_count);
-        meshes = Vec::with_capacity(mesh_count);
-
-        for j in 0..mesh_count {
-            let mesh = &*(*(*ptr).mMeshes.add(j));
-            let vertex_count = mesh.mNumVertices as usize;
-            let face_count = mesh.mNumFaces as usize;
-
-            // println!("vc: {}, fc: {}", vertex_count, face_count);
-
-            let mut uvs = vec![];
-            let mut pts = vec![];
-            let mut faces = vec![];
-            let mut normals = vec![];
-
-            // println!("Vertices of mesh: {}", vertex_count);
+}
 
-            for i in 0..vertex_count {
-                pts.push(*mesh.mVertices.add(i));
-                normals.push(*mesh.mNormals.add(i));
-                uvs.push((*mesh.mTextureCoords[0].add(i)).xy());
-            }
+/// Imports a file with triangulation and generated smooth normals and uv
+/// coordinates, one mesh per assimp mesh.
+///
+/// Unlike `aiImportFileToMesh` the uvs are not flipped and keep their
+/// bottom-left origin. Only triangles are kept, the lines and points of a
+/// mesh are dropped. Normals and uvs are `None` for meshes without them.
+pub fn aiImportFileToMeshes(file: &str) -> Result<Vec<Mesh>, Error> {
+    let scene = Importer::new()
+        .steps(
+            aiPostProcessSteps::Triangulate
+                | aiPostProcessSteps::GenSmoothNormals
+                | aiPostProcessSteps::GenUVCoords,
+        )
+        .read_file(file)?;
 
-            let mut mn = 10000000;
-            let mut mx = 0;
-
-            for i in 0..face_count {
-                let face = &*mesh.mFaces.add(i);
-                let a = *face.mIndices.add(0);
-                let b = *face.mIndices.add(1);
-                let c = *face.mIndices.add(2);
-                faces.push(MeshFace::new(a, b, c));
-                mn = a.min(b.min(c.min(mn)));
-                mx = a.max(b.max(c.max(mx)));
-            }
-            // println!("face range: {}..{}", mn, mx);
-
-            meshes.push(Mesh {
-                points: pts,
-                normals: Some(normals),
-                faces,
-                uvcoords: Some(uvs),
-            });
-        }
-    }
-    aiReleaseImport(ptr);
+    let meshes = scene
+        .mesh_views()
+        .map(|mesh| Mesh {
+            points: mesh.positions().to_vec(),
+            normals: if mesh.normals().is_empty() {
+                None
+            } else {
+                Some(mesh.normals().to_vec())
+            },
+            faces: mesh
+                .faces()
+                .filter(|face| face.len() == 3)
+                .map(|face| MeshFace::new(face[0], face[1], face[2]))
+                .collect(),
+            uvcoords: mesh
+                .uv_channel(0)
+                .map(|uvs| uvs.iter().map(|uv| uv.xy()).collect()),
+        })
+        .collect();
 
-    Some(meshes)
+    Ok(meshes)
 }
@@ -1,9 +1,85 @@
+/// Declares a C enum as a `#[repr(u32)]` Rust enum with a checked
+/// conversion from the raw value, values read from assimp always go
+/// through `from_raw`.
+macro_rules! c_enum {
+    ($(#[$meta:meta])* pub enum $name:ident { $($(#[$vmeta:meta])* $variant:ident = $value:literal,)* }) => {
+        $(#[$meta])*
+        #[repr(u32)]
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant = $value,)*
+        }
+
+        impl $name {
+            // the variant attributes are repeated to keep their `cfg` gates
+
+            /// Every value of the enum, in declaration order.
+            #[allow(unused_doc_comments)]
+            pub const ALL: &'static [$name] = &[$($(#[$vmeta])* $name::$variant,)*];
+
+            /// Converts the raw value used by the C API, `None` for unknown values.
+            #[allow(unused_doc_comments)]
+            pub fn from_raw(value: ::std::os::raw::c_uint) -> Option<$name> {
+                match value {
+                    $($(#[$vmeta])* $value => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<$name> for ::std::os::raw::c_uint {
+            fn from(value: $name) -> ::std::os::raw::c_uint {
+                value as ::std::os::raw::c_uint
+            }
+        }
+    };
+}
+
+mod anim;
+mod camera;
+mod cfileio;
+mod cimport;
+mod config;
 mod face;
+mod light;
+mod material;
+mod matrix4x4;
 mod mesh;
+mod metadata;
+mod quaternion;
 mod scene;
+mod string;
+mod texture;
+mod vector2;
 mod vector3;
 
+pub use self::anim::*;
+pub use self::camera::aiCamera;
+pub use self::cfileio::*;
+pub use self::cimport::{aiLogStream, aiLogStreamCallback, aiPropertyStore};
+pub use self::config::*;
 pub use self::face::aiFace;
-pub use self::mesh::aiMesh;
-pub use self::scene::aiScene;
+pub use self::light::{aiColor3D, aiLight, aiLightSourceType};
+pub use self::material::*;
+pub use self::matrix4x4::aiMatrix4x4;
+pub use self::mesh::{
+    aiAABB, aiAnimMesh, aiBone, aiColor4D, aiMesh, aiMorphingMethod, aiPrimitiveType_LINE,
+    aiPrimitiveType_NGONEncodingFlag, aiPrimitiveType_POINT, aiPrimitiveType_POLYGON,
+    aiPrimitiveType_TRIANGLE, aiVertexWeight, AI_MAX_BONE_WEIGHTS, AI_MAX_FACES,
+    AI_MAX_FACE_INDICES, AI_MAX_NUMBER_OF_COLOR_SETS, AI_MAX_NUMBER_OF_TEXTURECOORDS,
+    AI_MAX_VERTICES,
+};
+pub use self::metadata::{
+    aiMetadata, aiMetadataEntry, aiMetadataType, AI_METADATA_SOURCE_COPYRIGHT,
+    AI_METADATA_SOURCE_FORMAT, AI_METADATA_SOURCE_FORMAT_VERSION, AI_METADATA_SOURCE_GENERATOR,
+};
+pub use self::quaternion::aiQuaternion;
+pub use self::scene::{
+    aiNode, aiScene, AI_SCENE_FLAGS_ALLOW_SHARED, AI_SCENE_FLAGS_INCOMPLETE,
+    AI_SCENE_FLAGS_NON_VERBOSE_FORMAT, AI_SCENE_FLAGS_TERRAIN, AI_SCENE_FLAGS_VALIDATED,
+    AI_SCENE_FLAGS_VALIDATION_WARNING,
+};
+pub use self::string::{aiString, MAXLEN};
+pub use self::texture::{aiTexel, aiTexture, HINTMAXTEXTURELEN};
+pub use self::vector2::aiVector2D;
 pub use self::vector3::aiVector3D;
@@ -0,0 +1,262 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use std::os::raw::c_uint;
+
+use super::{aiQuaternion, aiString, aiVector3D};
+
+#[cfg(assimp_5_3)]
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** Interpolation mode of a key, added in assimp 5.3.
+     */
+    pub enum aiAnimInterpolation {
+        /** The value of the key is held until the next key. */
+        Step = 0,
+
+        /** Linear interpolation towards the next key. */
+        Linear = 1,
+
+        /** Spherical linear interpolation towards the next key. */
+        Spherical_Linear = 2,
+
+        /** Cubic spline interpolation towards the next key. */
+        Cubic_Spline = 3,
+    }
+}
+
+// ---------------------------------------------------------------------------
+/** A time-value pair specifying a certain 3D vector for the given time. */
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct aiVectorKey {
+    /** The time of this key */
+    pub mTime: f64,
+
+    /** The value of this key */
+    pub mValue: aiVector3D,
+
+    /** The interpolation setting of this key, an aiAnimInterpolation value. */
+    #[cfg(assimp_5_3)]
+    pub mInterpolation: c_uint,
+}
+
+// ---------------------------------------------------------------------------
+/** A time-value pair specifying a rotation for the given time.
+ *  Rotations are expressed with quaternions. */
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct aiQuatKey {
+    /** The time of this key */
+    pub mTime: f64,
+
+    /** The value of this key */
+    pub mValue: aiQuaternion,
+
+    /** The interpolation setting of this key, an aiAnimInterpolation value. */
+    #[cfg(assimp_5_3)]
+    pub mInterpolation: c_uint,
+}
+
+// ---------------------------------------------------------------------------
+/** Binds a anim-mesh to a specific point in time. */
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct aiMeshKey {
+    /** The time of this key */
+    pub mTime: f64,
+
+    /** Index into the aiMesh::mAnimMeshes array of the
+     *  mesh corresponding to the #aiMeshAnim hosting this
+     *  key frame. The referenced anim mesh is evaluated
+     *  according to the rules defined in the docs for #aiAnimMesh.*/
+    pub mValue: c_uint,
+}
+
+// ---------------------------------------------------------------------------
+/** Binds a morph anim mesh to a specific point in time. */
+#[repr(C)]
+pub struct aiMeshMorphKey {
+    /** The time of this key */
+    pub mTime: f64,
+
+    /** The values and weights at the time of this key
+     *   - mValues: index of attachment mesh to apply weight at the same position in mWeights
+     *   - mWeights: weight to apply to the blend shape index at the same position in mValues
+     */
+    pub mValues: *const c_uint,
+    pub mWeights: *const f64,
+
+    /** The number of values and weights */
+    pub mNumValuesAndWeights: c_uint,
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** Defines how an animation channel behaves outside the defined time
+     *  range. This corresponds to aiNodeAnim::mPreState and
+     *  aiNodeAnim::mPostState.*/
+    pub enum aiAnimBehaviour {
+        /** The value from the default node transformation is taken*/
+        Default = 0x0,
+
+        /** The nearest key value is used without interpolation */
+        Constant = 0x1,
+
+        /** The value of the nearest two keys is linearly
+         *  extrapolated for the current time value.*/
+        Linear = 0x2,
+
+        /** The animation is repeated.
+         *
+         *  If the animation key go from n to m and the current
+         *  time is t, use the value at (t-n) % (|m-n|).*/
+        Repeat = 0x3,
+    }
+}
+
+// ---------------------------------------------------------------------------
+/** Describes the animation of a single node. The name specifies the
+ *  bone/node which is affected by this animation channel. The keyframes
+ *  are given in three separate series of values, one each for position,
+ *  rotation and scaling. The transformation matrix computed from these
+ *  values replaces the node's original transformation matrix at a
+ *  specific time.
+ *  This means all keys are absolute and not relative to the bone default pose.
+ *  The order in which the transformations are applied is
+ *  - as usual - scaling, rotation, translation.
+ *
+ *  @note All keys are returned in their correct, chronological order.
+ *  Duplicate keys don't pass the validation step. Most likely there
+ *  will be no negative time values, but they are not forbidden also ( so
+ *  implementations need to cope with them! ) */
+#[repr(C)]
+pub struct aiNodeAnim {
+    /** The name of the node affected by this animation. The node
+     *  must exist and it must be unique.*/
+    pub mNodeName: aiString,
+
+    /** The number of position keys */
+    pub mNumPositionKeys: c_uint,
+
+    /** The position keys of this animation channel. Positions are
+     * specified as 3D vector. The array is mNumPositionKeys in size.
+     *
+     * If there are position keys, there will also be at least one
+     * scaling and one rotation key.*/
+    pub mPositionKeys: *const aiVectorKey,
+
+    /** The number of rotation keys */
+    pub mNumRotationKeys: c_uint,
+
+    /** The rotation keys of this animation channel. Rotations are
+     *  given as quaternions,  which are 4D vectors. The array is
+     *  mNumRotationKeys in size.
+     *
+     * If there are rotation keys, there will also be at least one
+     * scaling and one position key. */
+    pub mRotationKeys: *const aiQuatKey,
+
+    /** The number of scaling keys */
+    pub mNumScalingKeys: c_uint,
+
+    /** The scaling keys of this animation channel. Scalings are
+     *  specified as 3D vector. The array is mNumScalingKeys in size.
+     *
+     * If there are scaling keys, there will also be at least one
+     * position and one rotation key.*/
+    pub mScalingKeys: *const aiVectorKey,
+
+    /** Defines how the animation behaves before the first
+     *  key is encountered, an aiAnimBehaviour value.
+     *
+     *  The default value is aiAnimBehaviour_DEFAULT (the original
+     *  transformation matrix of the affected node is used).*/
+    pub mPreState: c_uint,
+
+    /** Defines how the animation behaves after the last
+     *  key was processed, an aiAnimBehaviour value.
+     *
+     *  The default value is aiAnimBehaviour_DEFAULT (the original
+     *  transformation matrix of the affected node is taken).*/
+    pub mPostState: c_uint,
+}
+
+// ---------------------------------------------------------------------------
+/** Describes vertex-based animations for a single mesh or a group of
+ *  meshes. Meshes carry the animation data for each frame in their
+ *  aiMesh::mAnimMeshes array. The purpose of aiMeshAnim is to
+ *  define keyframes linking each mesh attachment to a particular
+ *  point in time. */
+#[repr(C)]
+pub struct aiMeshAnim {
+    /** Name of the mesh to be animated. An empty string is not allowed,
+     *  animated meshes need to be named (not necessarily uniquely,
+     *  the name can basically serve as wild-card to select a group
+     *  of meshes with similar animation setup)*/
+    pub mName: aiString,
+
+    /** Size of the #mKeys array. Must be 1, at least. */
+    pub mNumKeys: c_uint,
+
+    /** Key frames of the animation. May not be nullptr. */
+    pub mKeys: *const aiMeshKey,
+}
+
+// ---------------------------------------------------------------------------
+/** Describes a morphing animation of a given mesh. */
+#[repr(C)]
+pub struct aiMeshMorphAnim {
+    /** Name of the mesh to be animated. An empty string is not allowed,
+     *  animated meshes need to be named (not necessarily uniquely,
+     *  the name can basically serve as wildcard to select a group
+     *  of meshes with similar animation setup)*/
+    pub mName: aiString,
+
+    /** Size of the #mKeys array. Must be 1, at least. */
+    pub mNumKeys: c_uint,
+
+    /** Key frames of the animation. May not be nullptr. */
+    pub mKeys: *const aiMeshMorphKey,
+}
+
+// ---------------------------------------------------------------------------
+/** An animation consists of key-frame data for a number of nodes. For
+ *  each node affected by the animation a separate series of data is given.*/
+#[repr(C)]
+pub struct aiAnimation {
+    /** The name of the animation. If the modeling package this data was
+     *  exported from does support only a single animation channel, this
+     *  name is usually empty (length is zero). */
+    pub mName: aiString,
+
+    /** Duration of the animation in ticks.  */
+    pub mDuration: f64,
+
+    /** Ticks per second. 0 if not specified in the imported file */
+    pub mTicksPerSecond: f64,
+
+    /** The number of bone animation channels. Each channel affects
+     *  a single node. */
+    pub mNumChannels: c_uint,
+
+    /** The node animation channels. Each channel affects a single node.
+     *  The array is mNumChannels in size. */
+    pub mChannels: *const *const aiNodeAnim,
+
+    /** The number of mesh animation channels. Each channel affects
+     *  a single mesh and defines vertex-based animation. */
+    pub mNumMeshChannels: c_uint,
+
+    /** The mesh animation channels. Each channel affects a single mesh.
+     *  The array is mNumMeshChannels in size. */
+    pub mMeshChannels: *const *const aiMeshAnim,
+
+    /** The number of mesh animation channels. Each channel affects
+     *  a single mesh and defines morphing animation. */
+    pub mNumMorphMeshChannels: c_uint,
+
+    /** The morph mesh animation channels. Each channel affects a single mesh.
+     *  The array is mNumMorphMeshChannels in size. */
+    pub mMorphMeshChannels: *const *const aiMeshMorphAnim,
+}
@@ -0,0 +1,102 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_uint};
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** Seek origins (for the virtual file system API).
+     *  Much cooler than using SEEK_SET, SEEK_CUR or SEEK_END.
+     */
+    pub enum aiOrigin {
+        /** Beginning of the file */
+        Set = 0x0,
+
+        /** Current position of the file pointer */
+        Cur = 0x1,
+
+        /** End of the file, offsets must be negative */
+        End = 0x2,
+    }
+}
+
+// aiFile callbacks
+pub type aiFileWriteProc =
+    Option<unsafe extern "C" fn(*mut aiFile, *const c_char, usize, usize) -> usize>;
+pub type aiFileReadProc =
+    Option<unsafe extern "C" fn(*mut aiFile, *mut c_char, usize, usize) -> usize>;
+pub type aiFileTellProc = Option<unsafe extern "C" fn(*mut aiFile) -> usize>;
+pub type aiFileFlushProc = Option<unsafe extern "C" fn(*mut aiFile)>;
+/** The origin is an aiOrigin value, the result an aiReturn value. */
+pub type aiFileSeek = Option<unsafe extern "C" fn(*mut aiFile, usize, c_uint) -> c_int>;
+
+// aiFileIO callbacks
+pub type aiFileOpenProc =
+    Option<unsafe extern "C" fn(*mut aiFileIO, *const c_char, *const c_char) -> *mut aiFile>;
+pub type aiFileCloseProc = Option<unsafe extern "C" fn(*mut aiFileIO, *mut aiFile)>;
+
+// Represents user-defined data
+pub type aiUserData = *mut c_char;
+
+// ----------------------------------------------------------------------------------
+/** @brief C-API: File system callbacks
+ *
+ *  Provided are functions to open and close files. Supply a custom structure to
+ *  the import function. If you don't, a default implementation is used. Use custom
+ *  file systems to enable reading from other sources, such as ZIPs
+ *  or memory locations. */
+#[repr(C)]
+pub struct aiFileIO {
+    /** Function used to open a new file
+     */
+    pub OpenProc: aiFileOpenProc,
+
+    /** Function used to close an existing file
+     */
+    pub CloseProc: aiFileCloseProc,
+
+    /** User-defined, opaque data */
+    pub UserData: aiUserData,
+}
+
+// ----------------------------------------------------------------------------------
+/** @brief C-API: File callbacks
+ *
+ *  Actually, it's a data structure to wrap a set of fXXXX (e.g fopen)
+ *  replacement functions.
+ *
+ *  The default implementation of the functions utilizes the fXXX functions from
+ *  the CRT. However, you can supply a custom implementation to Assimp by
+ *  delivering a custom aiFileIO. Use this to enable reading from other sources,
+ *  such as ZIP archives or memory locations. */
+#[repr(C)]
+pub struct aiFile {
+    /** Callback to read from a file */
+    pub ReadProc: aiFileReadProc,
+
+    /** Callback to write to a file */
+    pub WriteProc: aiFileWriteProc,
+
+    /** Callback to retrieve the current position of
+     *  the file cursor (ftell())
+     */
+    pub TellProc: aiFileTellProc,
+
+    /** Callback to retrieve the size of the file,
+     *  in bytes
+     */
+    pub FileSizeProc: aiFileTellProc,
+
+    /** Callback to set the current position
+     * of the file cursor (fseek())
+     */
+    pub SeekProc: aiFileSeek,
+
+    /** Callback to flush the file contents
+     */
+    pub FlushProc: aiFileFlushProc,
+
+    /** User-defined, opaque data
+     */
+    pub UserData: aiUserData,
+}
@@ -0,0 +1,41 @@
+#![allow(non_camel_case_types)]
+
+use std::os::raw::c_char;
+
+// --------------------------------------------------------------------------------
+/** Represents an opaque set of settings to be used during importing.
+ *  @see aiCreatePropertyStore
+ *  @see aiReleasePropertyStore
+ *  @see aiImportFileExWithProperties
+ *  @see aiSetPropertyInteger
+ *  @see aiSetPropertyFloat
+ *  @see aiSetPropertyString
+ *  @see aiSetPropertyMatrix
+ */
+// --------------------------------------------------------------------------------
+#[repr(C)]
+pub struct aiPropertyStore {
+    sentinel: c_char,
+}
+
+// --------------------------------------------------------------------------------
+/** Callback receiving a log message and the user data of its #aiLogStream. */
+pub type aiLogStreamCallback = Option<unsafe extern "C" fn(*const c_char, *mut c_char)>;
+
+// --------------------------------------------------------------------------------
+/** C-API: Represents a log stream. A log stream receives all log messages and
+ *  streams them _somewhere_.
+ *  @see aiGetPredefinedLogStream
+ *  @see aiAttachLogStream
+ *  @see aiDetachLogStream
+ */
+// --------------------------------------------------------------------------------
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct aiLogStream {
+    /** callback to be called */
+    pub callback: aiLogStreamCallback,
+
+    /** user data to be passed to the callback */
+    pub user: *mut c_char,
+}
@@ -0,0 +1,326 @@
+#![allow(non_upper_case_globals)]
+
+use std::os::raw::c_uint;
+
+// ###########################################################################
+// GENERAL SETTINGS (for all importers and post-processing steps)
+// ###########################################################################
+
+// ---------------------------------------------------------------------------
+/** @brief Specifies the maximum angle that may be between two vertex tangents
+ *         that their tangents and bi-tangents are smoothed.
+ *
+ * This applies to the CalcTangentSpace-Step. The angle is specified
+ * in degrees. The maximum value is 175.
+ * Property type: float. Default value: 45 degrees
+ */
+pub const AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE: &str = "PP_CT_MAX_SMOOTHING_ANGLE";
+
+// ---------------------------------------------------------------------------
+/** @brief  Specifies the maximum angle that may be between two face normals
+ *          at the same vertex position that their are smoothed together.
+ *
+ * Sometimes referred to as 'crease angle'.
+ * This applies to the GenSmoothNormals-Step. The angle is specified
+ * in degrees, so 180 is PI. The default value is 175 degrees (all vertex
+ * normals are smoothed). The maximum value is 175, too. Property type: float.
+ * Warning: setting this option may cause a severe loss of performance. The
+ * performance is unaffected if the #AI_CONFIG_FAVOUR_SPEED flag is set but
+ * the output quality may be reduced.
+ */
+pub const AI_CONFIG_PP_GSN_MAX_SMOOTHING_ANGLE: &str = "PP_GSN_MAX_SMOOTHING_ANGLE";
+
+// ---------------------------------------------------------------------------
+/** @brief  Configures the #aiProcess_RemoveRedundantMaterials step to
+ *  keep materials matching a name in a given list.
+ *
+ * This is a list of 1 to n strings, ' ' serves as delimiter character.
+ * Identifiers containing whitespaces must be enclosed in *single*
+ * quotation marks. For example:<tt>
+ * "keep-me and_me_to anotherMaterialToBeKept \'name with whitespace\'"</tt>.
+ * If a material matches on of these names, it will not be modified or
+ * removed by the postprocessing step nor will other materials be replaced
+ * by a reference to it. <br>
+ * This option might be useful if you are using some magic material names
+ * to pass additional semantics through the content pipeline. This ensures
+ * they won't be optimized away, but a general optimization is still
+ * performed for materials not contained in the list.
+ * Property type: String. Default value: n/a
+ * @note Linefeeds, tabs or carriage returns are treated as whitespace.
+ *   Material names are case sensitive.
+ */
+pub const AI_CONFIG_PP_RRM_EXCLUDE_LIST: &str = "PP_RRM_EXCLUDE_LIST";
+
+// ---------------------------------------------------------------------------
+/** @brief  Configures the #aiProcess_PreTransformVertices step to
+ *  keep the scene hierarchy. Meshes are moved to worldspace, but
+ *  no optimization is performed (read: meshes with equal materials are not
+ *  joined. The total number of meshes won't change).
+ *
+ * This option could be of use for you if the scene hierarchy contains
+ * important additional information which you intend to parse.
+ * For rendering, you can still render all meshes in the scene without
+ * any transformations.
+ * Property type: bool. Default value: false.
+ */
+pub const AI_CONFIG_PP_PTV_KEEP_HIERARCHY: &str = "PP_PTV_KEEP_HIERARCHY";
+
+// ---------------------------------------------------------------------------
+/** @brief Configures the #aiProcess_PreTransformVertices step to normalize
+ *  all vertex components into the [-1,1] range. That is, a bounding box
+ *  for the whole scene is computed, the maximum component is taken and all
+ *  meshes are scaled appropriately (uniformly of course!).
+ *  This might be useful if you don't know the spatial dimension of the input
+ *  data*/
+pub const AI_CONFIG_PP_PTV_NORMALIZE: &str = "PP_PTV_NORMALIZE";
+
+// ---------------------------------------------------------------------------
+/** @brief Configures the #aiProcess_PreTransformVertices step to use
+ *  a users defined matrix as the scene root node transformation before
+ *  transforming vertices.
+ *  Property type: bool. Default value: false.
+ */
+pub const AI_CONFIG_PP_PTV_ADD_ROOT_TRANSFORMATION: &str = "PP_PTV_ADD_ROOT_TRANSFORMATION";
+
+// ---------------------------------------------------------------------------
+/** @brief Configures the #aiProcess_PreTransformVertices step to use
+ *  a users defined matrix as the scene root node transformation before
+ *  transforming vertices. This property correspond to the 'a1' component
+ *  of the transformation matrix.
+ *  Property type: aiMatrix4x4.
+ */
+pub const AI_CONFIG_PP_PTV_ROOT_TRANSFORMATION: &str = "PP_PTV_ROOT_TRANSFORMATION";
+
+// ---------------------------------------------------------------------------
+/** @brief Configures the #aiProcess_FindDegenerates step to
+ *  remove degenerated primitives from the import - immediately.
+ *
+ * The default behaviour converts degenerated triangles to lines and
+ * degenerated lines to points. See the documentation to the
+ * #aiProcess_FindDegenerates step for a detailed example of the various ways
+ * to get rid of these lines and points if you don't want them.
+ * Property type: bool. Default value: false.
+ */
+pub const AI_CONFIG_PP_FD_REMOVE: &str = "PP_FD_REMOVE";
+
+// ---------------------------------------------------------------------------
+/**
+ *  @brief  Configures the #aiProcess_FindDegenerates to check the area of a
+ *  triangle to be greater than e-6. If this is not the case the triangle will
+ *  be removed if #AI_CONFIG_PP_FD_REMOVE is set to true.
+ */
+pub const AI_CONFIG_PP_FD_CHECKAREA: &str = "PP_FD_CHECKAREA";
+
+// ---------------------------------------------------------------------------
+/** @brief Configures the #aiProcess_OptimizeGraph step to preserve nodes
+ * matching a name in a given list.
+ *
+ * This is a list of 1 to n strings, ' ' serves as delimiter character.
+ * Identifiers containing whitespaces must be enclosed in *single*
+ * quotation marks. For example:<tt>
+ * "keep-me and_me_to anotherNodeToBeKept \'name with whitespace\'"</tt>.
+ * If a node matches on of these names, it will not be modified or
+ * removed by the postprocessing step.<br>
+ * This option might be useful if you are using some magic node names
+ * to pass additional semantics through the content pipeline. This ensures
+ * they won't be optimized away, but a general optimization is still
+ * performed for nodes not contained in the list.
+ * Property type: String. Default value: n/a
+ * @note Linefeeds, tabs or carriage returns are treated as whitespace.
+ *   Node names are case sensitive.
+ */
+pub const AI_CONFIG_PP_OG_EXCLUDE_LIST: &str = "PP_OG_EXCLUDE_LIST";
+
+// ---------------------------------------------------------------------------
+/** @brief  Set the maximum number of triangles in a mesh.
+ *
+ * This is used by the "SplitLargeMeshes" PostProcess-Step to determine
+ * whether a mesh must be split or not.
+ * @note The default value is AI_SLM_DEFAULT_MAX_TRIANGLES
+ * Property type: integer.
+ */
+pub const AI_CONFIG_PP_SLM_TRIANGLE_LIMIT: &str = "PP_SLM_TRIANGLE_LIMIT";
+
+/** @brief The default value for the AI_CONFIG_PP_SLM_TRIANGLE_LIMIT setting */
+pub const AI_SLM_DEFAULT_MAX_TRIANGLES: c_uint = 1000000;
+
+// ---------------------------------------------------------------------------
+/** @brief  Set the maximum number of vertices in a mesh.
+ *
+ * This is used by the "SplitLargeMeshes" PostProcess-Step to determine
+ * whether a mesh must be split or not.
+ * @note The default value is AI_SLM_DEFAULT_MAX_VERTICES
+ * Property type: integer.
+ */
+pub const AI_CONFIG_PP_SLM_VERTEX_LIMIT: &str = "PP_SLM_VERTEX_LIMIT";
+
+/** @brief The default value for the AI_CONFIG_PP_SLM_VERTEX_LIMIT setting */
+pub const AI_SLM_DEFAULT_MAX_VERTICES: c_uint = 1000000;
+
+// ---------------------------------------------------------------------------
+/** @brief Set the maximum number of bones affecting a single vertex
+ *
+ * This is used by the #aiProcess_LimitBoneWeights PostProcess-Step.
+ * @note The default value is AI_LMW_MAX_WEIGHTS
+ * Property type: integer.*/
+pub const AI_CONFIG_PP_LBW_MAX_WEIGHTS: &str = "PP_LBW_MAX_WEIGHTS";
+
+/** @brief The default value for the AI_CONFIG_PP_LBW_MAX_WEIGHTS setting */
+pub const AI_LMW_MAX_WEIGHTS: c_uint = 0x4;
+
+// ---------------------------------------------------------------------------
+/** @brief Lower the deboning threshold in order to remove more bones.
+ *
+ * This is used by the #aiProcess_Debone PostProcess-Step.
+ * @note The default value is AI_DEBONE_THRESHOLD
+ * Property type: float.*/
+pub const AI_CONFIG_PP_DB_THRESHOLD: &str = "PP_DB_THRESHOLD";
+
+/** @brief The default value for the AI_CONFIG_PP_DB_THRESHOLD setting */
+pub const AI_DEBONE_THRESHOLD: f32 = 1.0;
+
+// ---------------------------------------------------------------------------
+/** @brief Require all bones qualify for deboning before removing any
+ *
+ * This is used by the #aiProcess_Debone PostProcess-Step.
+ * @note The default value is 0
+ * Property type: bool.*/
+pub const AI_CONFIG_PP_DB_ALL_OR_NONE: &str = "PP_DB_ALL_OR_NONE";
+
+/** @brief Default value for the #AI_CONFIG_PP_ICL_PTCACHE_SIZE property
+ */
+pub const PP_ICL_PTCACHE_SIZE: c_uint = 12;
+
+// ---------------------------------------------------------------------------
+/** @brief Set the size of the post-transform vertex cache to optimize the
+ *    vertices for. This configures the #aiProcess_ImproveCacheLocality step.
+ *
+ * The size is given in vertices. Of course you can't know how the vertex
+ * format will exactly look like after the import returns, but you can still
+ * guess what your meshes will probably have.
+ * @note The default value is #PP_ICL_PTCACHE_SIZE. That results in slight
+ * performance improvements for most nVidia/AMD cards since 2002.
+ * Property type: integer.
+ */
+pub const AI_CONFIG_PP_ICL_PTCACHE_SIZE: &str = "PP_ICL_PTCACHE_SIZE";
+
+// ---------------------------------------------------------------------------
+// Enumerates components of the aiScene and aiMesh data structures
+// that can be excluded from the import using the #aiProcess_RemoveComponent step.
+//
+// See the documentation to #aiProcess_RemoveComponent for more details.
+
+/** Normal vectors */
+pub const aiComponent_NORMALS: c_uint = 0x2;
+
+/** Tangents and bitangents go always together ... */
+pub const aiComponent_TANGENTS_AND_BITANGENTS: c_uint = 0x4;
+
+/** ALL color sets
+ * Use aiComponent_COLORn(N) to specify the N'th set */
+pub const aiComponent_COLORS: c_uint = 0x8;
+
+/** ALL texture UV sets
+ * aiComponent_TEXCOORDn(N) to specify the N'th set  */
+pub const aiComponent_TEXCOORDS: c_uint = 0x10;
+
+/** Removes all bone weights from all meshes.
+ * The corresponding scenegraph nodes are NOT removed.
+ * use the #aiProcess_OptimizeGraph step to do this */
+pub const aiComponent_BONEWEIGHTS: c_uint = 0x20;
+
+/** Removes all node animations (aiScene::mAnimations).
+ * The corresponding scenegraph nodes are NOT removed.
+ * use the #aiProcess_OptimizeGraph step to do this */
+pub const aiComponent_ANIMATIONS: c_uint = 0x40;
+
+/** Removes all embedded textures (aiScene::mTextures) */
+pub const aiComponent_TEXTURES: c_uint = 0x80;
+
+/** Removes all light sources (aiScene::mLights).
+ * The corresponding scenegraph nodes are NOT removed.
+ * use the #aiProcess_OptimizeGraph step to do this */
+pub const aiComponent_LIGHTS: c_uint = 0x100;
+
+/** Removes all cameras (aiScene::mCameras).
+ * The corresponding scenegraph nodes are NOT removed.
+ * use the #aiProcess_OptimizeGraph step to do this */
+pub const aiComponent_CAMERAS: c_uint = 0x200;
+
+/** Removes all meshes (aiScene::mMeshes). */
+pub const aiComponent_MESHES: c_uint = 0x400;
+
+/** Removes all materials. One default material will
+ * be generated, so aiScene::mNumMaterials will be 1. */
+pub const aiComponent_MATERIALS: c_uint = 0x800;
+
+// ---------------------------------------------------------------------------
+/** @brief Input parameter to the #aiProcess_RemoveComponent step:
+ *  Specifies the parts of the data structure to be removed.
+ *
+ * See the documentation to this step for further details. The property
+ * is expected to be an integer, a bitwise combination of the
+ * #aiComponent flags defined above in this header. The default
+ * value is 0. Important: if no valid mesh is remaining after the
+ * step has been executed (e.g you thought it was funny to specify ALL
+ * of the flags defined above) the import FAILS. Mainly because there is
+ * no data to work on anymore ...
+ */
+pub const AI_CONFIG_PP_RVC_FLAGS: &str = "PP_RVC_FLAGS";
+
+// ---------------------------------------------------------------------------
+/** @brief Input parameter to the #aiProcess_SortByPType step:
+ *  Specifies which primitive types are removed by the step.
+ *
+ *  This is a bitwise combination of the aiPrimitiveType flags.
+ *  Specifying all of them is illegal, of course. A typical use would
+ *  be to exclude all line and point meshes from the import. This
+ *  is an integer property, its default value is 0.
+ */
+pub const AI_CONFIG_PP_SBP_REMOVE: &str = "PP_SBP_REMOVE";
+
+// ---------------------------------------------------------------------------
+/** @brief Input parameter to the #aiProcess_FindInvalidData step:
+ *  Specifies the floating-point accuracy for animation values. The step
+ *  checks for animation tracks where all frame values are absolutely equal
+ *  and removes them. This tweakable controls the epsilon for floating-point
+ *  comparisons - two keys are considered equal if the invariant
+ *  abs(n0-n1)>epsilon holds true for all vector respectively quaternion
+ *  components. The default value is 0.f - comparisons are exact then.
+ */
+pub const AI_CONFIG_PP_FID_ANIM_ACCURACY: &str = "PP_FID_ANIM_ACCURACY";
+
+// TransformUVCoords evaluates UV scalings
+pub const AI_UVTRAFO_SCALING: c_uint = 0x1;
+
+// TransformUVCoords evaluates UV rotations
+pub const AI_UVTRAFO_ROTATION: c_uint = 0x2;
+
+// TransformUVCoords evaluates UV translation
+pub const AI_UVTRAFO_TRANSLATION: c_uint = 0x4;
+
+// Everything baked together -> default value
+pub const AI_UVTRAFO_ALL: c_uint =
+    AI_UVTRAFO_SCALING | AI_UVTRAFO_ROTATION | AI_UVTRAFO_TRANSLATION;
+
+// ---------------------------------------------------------------------------
+/** @brief Input parameter to the #aiProcess_TransformUVCoords step:
+ *  Specifies which UV transformations are evaluated.
+ *
+ *  This is a bitwise combination of the AI_UVTRAFO_XXX flags (integer
+ *  property, of course). By default all transformations are enabled
+ * (AI_UVTRAFO_ALL).
+ */
+pub const AI_CONFIG_PP_TUV_EVALUATE: &str = "PP_TUV_EVALUATE";
+
+// ---------------------------------------------------------------------------
+/** @brief Specifies whether the importers and post-processing steps
+ *  use a global scale factor.
+ *
+ * Applied by the #aiProcess_GlobalScale step, the scene is scaled
+ * by this value. Property type: float. Default value: 1.0
+ */
+pub const AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY: &str = "GLOBAL_SCALE_FACTOR";
+
+/** @brief The default value for the AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY setting */
+pub const AI_CONFIG_GLOBAL_SCALE_FACTOR_DEFAULT: f32 = 1.0;
@@ -0,0 +1,580 @@
+#![allow(dead_code)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use std::os::raw::{c_char, c_int, c_uint};
+
+use super::aiString;
+
+// ---------------------------------------------------------------------------
+/** Standard return type for some library functions.
+ * Rarely used, and if, mostly in the C API.
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum aiReturn {
+    /** Indicates that a function was successful */
+    Success,
+
+    /** Indicates that a function failed */
+    Failure,
+
+    /** Indicates that not enough memory was available
+     * to perform the requested operation
+     */
+    OutOfMemory,
+}
+
+impl aiReturn {
+    pub fn from_raw(value: c_int) -> aiReturn {
+        match value {
+            0 => aiReturn::Success,
+            -3 => aiReturn::OutOfMemory,
+            _ => aiReturn::Failure,
+        }
+    }
+}
+
+impl From<aiReturn> for c_int {
+    fn from(value: aiReturn) -> c_int {
+        match value {
+            aiReturn::Success => 0,
+            aiReturn::Failure => -1,
+            aiReturn::OutOfMemory => -3,
+        }
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief Defines the purpose of a texture
+     *
+     *  This is a very difficult topic. Different 3D packages support different
+     *  kinds of textures. For very common texture types, such as bumpmaps, the
+     *  rendering results depend on implementation details in the rendering
+     *  pipelines of these applications. Assimp loads all texture references from
+     *  the model file and tries to determine which of the predefined texture
+     *  types below is the best choice to match the original use of the texture
+     *  as closely as possible.<br>
+     *
+     *  In content pipelines you'll usually define how textures have to be handled,
+     *  and the artists working on models have to conform to this specification,
+     *  regardless which 3D tool they're using.
+     */
+    pub enum aiTextureType {
+        /** Dummy value.
+         *
+         *  No texture, but the value to be used as 'texture semantic'
+         *  (#aiMaterialProperty::mSemantic) for all material properties
+         *  *not* related to textures.
+         */
+        None = 0,
+
+        /** The texture is combined with the result of the diffuse
+         *  lighting equation.
+         */
+        Diffuse = 1,
+
+        /** The texture is combined with the result of the specular
+         *  lighting equation.
+         */
+        Specular = 2,
+
+        /** The texture is combined with the result of the ambient
+         *  lighting equation.
+         */
+        Ambient = 3,
+
+        /** The texture is added to the result of the lighting
+         *  calculation. It isn't influenced by incoming light.
+         */
+        Emissive = 4,
+
+        /** The texture is a height map.
+         *
+         *  By convention, higher gray-scale values stand for
+         *  higher elevations from the base height.
+         */
+        Height = 5,
+
+        /** The texture is a (tangent space) normal-map.
+         *
+         *  Again, there are several conventions for tangent-space
+         *  normal maps. Assimp does (intentionally) not
+         *  distinguish here.
+         */
+        Normals = 6,
+
+        /** The texture defines the glossiness of the material.
+         *
+         *  The glossiness is in fact the exponent of the specular
+         *  (phong) lighting equation. Usually there is a conversion
+         *  function defined to map the linear color values in the
+         *  texture to a suitable exponent. Have fun.
+         */
+        Shininess = 7,
+
+        /** The texture defines per-pixel opacity.
+         *
+         *  Usually 'white' means opaque and 'black' means
+         *  'transparency'. Or quite the opposite. Have fun.
+         */
+        Opacity = 8,
+
+        /** Displacement texture
+         *
+         *  The exact purpose and format is application-dependent.
+         *  Higher color values stand for higher vertex displacements.
+         */
+        Displacement = 9,
+
+        /** Lightmap texture (aka Ambient Occlusion)
+         *
+         *  Both 'Lightmaps' and dedicated 'ambient occlusion maps' are
+         *  covered by this material property. The texture contains a
+         *  scaling value for the final color value of a pixel. Its
+         *  intensity is not affected by incoming light.
+         */
+        Lightmap = 10,
+
+        /** Reflection texture
+         *
+         * Contains the color of a perfect mirror reflection.
+         * Rarely used, almost never for real-time applications.
+         */
+        Reflection = 11,
+
+        /** PBR Materials
+         * PBR definitions from maya and other modelling packages now use this standard.
+         * This was originally introduced around 2012.
+         * Support for this is in game engines like Godot, Unreal or Unity3D.
+         * Modelling packages which use this are very common now.
+         */
+        BaseColor = 12,
+        NormalCamera = 13,
+        EmissionColor = 14,
+        Metalness = 15,
+        DiffuseRoughness = 16,
+        AmbientOcclusion = 17,
+
+        /** Unknown texture
+         *
+         *  A texture reference that does not match any of the definitions
+         *  above is considered to be 'unknown'. It is still imported,
+         *  but is excluded from any further post-processing.
+         */
+        Unknown = 18,
+
+        /** Sheen layer of the glTF `KHR_materials_sheen` extension.
+         */
+        #[cfg(assimp_5_1)]
+        Sheen = 19,
+
+        /** Clearcoat layer of the glTF `KHR_materials_clearcoat` extension.
+         */
+        #[cfg(assimp_5_1)]
+        Clearcoat = 20,
+
+        /** Transmission of the glTF `KHR_materials_transmission` extension.
+         */
+        #[cfg(assimp_5_1)]
+        Transmission = 21,
+
+        /** Maya material declarations
+         */
+        #[cfg(assimp_5_3)]
+        MayaBase = 22,
+        #[cfg(assimp_5_3)]
+        MayaSpecular = 23,
+        #[cfg(assimp_5_3)]
+        MayaSpecularColor = 24,
+        #[cfg(assimp_5_3)]
+        MayaSpecularRoughness = 25,
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief Defines all shading models supported by the library
+     *
+     *  The list of shading modes has been taken from Blender.
+     *  See Blender documentation for more information. The API does
+     *  not distinguish between "specular" and "diffuse" shaders (thus the
+     *  specular term for diffuse shading models like Oren-Nayar remains
+     *  undefined). <br>
+     *  Again, this value is just a hint. Assimp tries to select the shader whose
+     *  most common implementation matches the original rendering results of the
+     *  3D modeller which wrote a particular model as closely as possible.
+     */
+    pub enum aiShadingMode {
+        /** Flat shading. Shading is done on per-face base,
+         *  diffuse only. Also known as 'faceted shading'.
+         */
+        Flat = 0x1,
+
+        /** Simple Gouraud shading.
+         */
+        Gouraud = 0x2,
+
+        /** Phong-Shading -
+         */
+        Phong = 0x3,
+
+        /** Phong-Blinn-Shading
+         */
+        Blinn = 0x4,
+
+        /** Toon-Shading per pixel
+         *
+         *  Also known as 'comic' shader.
+         */
+        Toon = 0x5,
+
+        /** OrenNayar-Shading per pixel
+         *
+         *  Extension to standard Lambertian shading, taking the
+         *  roughness of the material into account
+         */
+        OrenNayar = 0x6,
+
+        /** Minnaert-Shading per pixel
+         *
+         *  Extension to standard Lambertian shading, taking the
+         *  "darkness" of the material into account
+         */
+        Minnaert = 0x7,
+
+        /** CookTorrance-Shading per pixel
+         *
+         *  Special shader for metallic surfaces.
+         */
+        CookTorrance = 0x8,
+
+        /** No shading at all. Constant light influence of 1.0.
+         * Also known as "Unlit"
+         */
+        NoShading = 0x9,
+
+        /** Fresnel shading
+         */
+        Fresnel = 0xa,
+
+        /** Physically-Based Rendering (PBR) shading using
+         * Bidirectional scattering/reflectance distribution function (BSDF/BRDF)
+         */
+        PbrBrdf = 0xb,
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief Defines how the mapping coords for a texture are generated.
+     *
+     *  Real-time applications typically require full UV coordinates, so the use of
+     *  the aiProcess_GenUVCoords step is highly recommended. It generates proper
+     *  UV channels for non-UV mapped objects, as long as an accurate description
+     *  how the mapping should look like (e.g spherical) is given.
+     *  See the #AI_MATKEY_MAPPING property for more details.
+     */
+    pub enum aiTextureMapping {
+        /** The mapping coordinates are taken from an UV channel.
+         *
+         *  The #AI_MATKEY_UVWSRC key specifies from which UV channel
+         *  the texture coordinates are to be taken from (remember,
+         *  meshes can have more than one UV channel).
+         */
+        UV = 0x0,
+
+        /** Spherical mapping */
+        Sphere = 0x1,
+
+        /** Cylindrical mapping */
+        Cylinder = 0x2,
+
+        /** Cubic mapping */
+        Box = 0x3,
+
+        /** Planar mapping */
+        Plane = 0x4,
+
+        /** Undefined mapping. Have fun. */
+        Other = 0x5,
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief Defines how the Nth texture of a specific type is combined with
+     *  the result of all previous layers.
+     *
+     *  Example (left: key, right: value): <br>
+     *  @code
+     *  DiffColor0     - gray
+     *  DiffTextureOp0 - aiTextureOpMultiply
+     *  DiffTexture0   - tex1.png
+     *  DiffTextureOp0 - aiTextureOpAdd
+     *  DiffTexture1   - tex2.png
+     *  @endcode
+     *  Written as equation, the final diffuse term for a specific pixel would be:
+     *  @code
+     *  diffFinal = DiffColor0 * sampleTex(DiffTexture0,UV0) +
+     *     sampleTex(DiffTexture1,UV0) * diffContrib;
+     *  @endcode
+     *  where 'diffContrib' is the intensity of the incoming light for that pixel.
+     */
+    pub enum aiTextureOp {
+        /** T = T1 * T2 */
+        Multiply = 0x0,
+
+        /** T = T1 + T2 */
+        Add = 0x1,
+
+        /** T = T1 - T2 */
+        Subtract = 0x2,
+
+        /** T = T1 / T2 */
+        Divide = 0x3,
+
+        /** T = (T1 + T2) - (T1 * T2) */
+        SmoothAdd = 0x4,
+
+        /** T = T1 + (T2-0.5) */
+        SignedAdd = 0x5,
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief Defines how UV coordinates outside the [0...1] range are handled.
+     *
+     *  Commonly referred to as 'wrapping mode'.
+     */
+    pub enum aiTextureMapMode {
+        /** A texture coordinate u|v is translated to u%1|v%1
+         */
+        Wrap = 0x0,
+
+        /** Texture coordinates outside [0...1]
+         *  are clamped to the nearest valid value.
+         */
+        Clamp = 0x1,
+
+        /** A texture coordinate u|v becomes u%1|v%1 if (u-(u%1))%2 is zero and
+         *  1-(u%1)|1-(v%1) otherwise
+         */
+        Mirror = 0x2,
+
+        /** If the texture coordinates for a pixel are outside [0...1]
+         *  the texture is not applied to that pixel
+         */
+        Decal = 0x3,
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief A very primitive RTTI system for the contents of material
+     *  properties.
+     */
+    pub enum aiPropertyTypeInfo {
+        /** Array of single-precision (32 Bit) floats
+         *
+         *  It is possible to use aiGetMaterialInteger[Array]() (or the C++-API
+         *  aiMaterial::Get()) to query properties stored in floating-point format.
+         *  The material system performs the type conversion automatically.
+         */
+        Float = 0x1,
+
+        /** Array of double-precision (64 Bit) floats
+         *
+         *  It is possible to use aiGetMaterialInteger[Array]() (or the C++-API
+         *  aiMaterial::Get()) to query properties stored in floating-point format.
+         *  The material system performs the type conversion automatically.
+         */
+        Double = 0x2,
+
+        /** The material property is an aiString.
+         *
+         *  Arrays of strings aren't possible, aiGetMaterialString() (or the
+         *  C++-API aiMaterial::Get()) *must* be used to query a string property.
+         */
+        String = 0x3,
+
+        /** Array of (32 Bit) integers
+         *
+         *  It is possible to use aiGetMaterialFloat[Array]() (or the C++-API
+         *  aiMaterial::Get()) to query properties stored in integer format.
+         *  The material system performs the type conversion automatically.
+         */
+        Integer = 0x4,
+
+        /** Simple binary buffer, content undefined. Not convertible to anything.
+         */
+        Buffer = 0x5,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// aiTextureFlags: defines some mixed flags for a particular texture.
+//
+// Usually you'll instruct your cg artists how textures have to look like ...
+// and how they will be processed in your application. However, if you use
+// Assimp for completely generic loading purposes you might also need to
+// process these flags in order to display as many 'unknown' 3D models as
+// possible correctly.
+//
+// This corresponds to the #AI_MATKEY_TEXFLAGS property.
+
+/** The texture's color values have to be inverted (component-wise 1-n)
+ */
+pub const aiTextureFlags_Invert: c_uint = 0x1;
+
+/** Explicit request to the application to process the alpha channel
+ *  of the texture.
+ *
+ *  Mutually exclusive with #aiTextureFlags_IgnoreAlpha. These
+ *  flags are set if the library can say for sure that the alpha
+ *  channel is used/is not used. If the model format does not
+ *  define this, it is left to the application to decide whether
+ *  the texture alpha channel - if any - is evaluated or not.
+ */
+pub const aiTextureFlags_UseAlpha: c_uint = 0x2;
+
+/** Explicit request to the application to ignore the alpha channel
+ *  of the texture.
+ *
+ *  Mutually exclusive with #aiTextureFlags_UseAlpha.
+ */
+pub const aiTextureFlags_IgnoreAlpha: c_uint = 0x4;
+
+// ---------------------------------------------------------------------------
+/** @brief Data structure for a single material property
+ *
+ *  As an user, you'll probably never need to deal with this data structure.
+ *  Just use the provided aiGetMaterialXXX() or aiMaterial::Get() family
+ *  of functions to query material properties easily. Processing them
+ *  manually is faster, but it is not the recommended way. It isn't worth
+ *  the effort. <br>
+ *  Material property names follow a simple scheme:
+ *  @code
+ *    $<name>
+ *    ?<name>
+ *       A public property, there must be corresponding AI_MATKEY_XXX define
+ *       2nd: Public, but ignored by the #aiProcess_RemoveRedundantMaterials
+ *       post-processing step.
+ *    ~<name>
+ *       A temporary property for internal use.
+ *  @endcode
+ *  @see aiMaterial
+ */
+#[repr(C)]
+pub struct aiMaterialProperty {
+    /** Specifies the name of the property (key)
+     *  Keys are generally case insensitive.
+     */
+    pub mKey: aiString,
+
+    /** Textures: Specifies their exact usage semantic.
+     * For non-texture properties, this member is always 0
+     * (or, better-said, #aiTextureType_NONE).
+     */
+    pub mSemantic: c_uint,
+
+    /** Textures: Specifies the index of the texture.
+     *  For non-texture properties, this member is always 0.
+     */
+    pub mIndex: c_uint,
+
+    /** Size of the buffer mData is pointing to, in bytes.
+     *  This value may not be 0.
+     */
+    pub mDataLength: c_uint,
+
+    /** Type information for the property.
+     *
+     * Defines the data layout inside the data buffer. This is used
+     * by the library internally to perform debug checks and to
+     * utilize proper type conversions.
+     * (It's probably a hacky solution, but it works.)
+     */
+    pub mType: c_uint,
+
+    /** Binary buffer to hold the property's value.
+     * The size of the buffer is always mDataLength.
+     */
+    pub mData: *const c_char,
+}
+
+// ---------------------------------------------------------------------------
+/** @brief Data structure for a material
+ *
+ *  Material data is stored using a key-value structure. A single key-value
+ *  pair is called a 'material property'. C++ users should use the provided
+ *  member functions of aiMaterial to process material properties, C users
+ *  have to stick with the aiMaterialGetXXX family of unbound functions.
+ *  The library defines a set of standard keys (AI_MATKEY_XXX).
+ */
+#[repr(C)]
+pub struct aiMaterial {
+    /** List of all material properties loaded. */
+    pub mProperties: *const *const aiMaterialProperty,
+
+    /** Number of properties in the data base */
+    pub mNumProperties: c_uint,
+
+    /** Storage allocated */
+    pub mNumAllocated: c_uint,
+}
+
+// ---------------------------------------------------------------------------
+// Material keys. Each key is used together with a texture type and index,
+// both 0 unless the key is one of the AI_MATKEY_TEXTURE_XXX keys.
+pub const AI_MATKEY_NAME: &str = "?mat.name";
+pub const AI_MATKEY_TWOSIDED: &str = "$mat.twosided";
+pub const AI_MATKEY_SHADING_MODEL: &str = "$mat.shadingm";
+pub const AI_MATKEY_ENABLE_WIREFRAME: &str = "$mat.wireframe";
+pub const AI_MATKEY_BLEND_FUNC: &str = "$mat.blend";
+pub const AI_MATKEY_OPACITY: &str = "$mat.opacity";
+pub const AI_MATKEY_TRANSPARENCYFACTOR: &str = "$mat.transparencyfactor";
+pub const AI_MATKEY_BUMPSCALING: &str = "$mat.bumpscaling";
+pub const AI_MATKEY_SHININESS: &str = "$mat.shininess";
+pub const AI_MATKEY_REFLECTIVITY: &str = "$mat.reflectivity";
+pub const AI_MATKEY_SHININESS_STRENGTH: &str = "$mat.shinpercent";
+pub const AI_MATKEY_REFRACTI: &str = "$mat.refracti";
+pub const AI_MATKEY_COLOR_DIFFUSE: &str = "$clr.diffuse";
+pub const AI_MATKEY_COLOR_AMBIENT: &str = "$clr.ambient";
+pub const AI_MATKEY_COLOR_SPECULAR: &str = "$clr.specular";
+pub const AI_MATKEY_COLOR_EMISSIVE: &str = "$clr.emissive";
+pub const AI_MATKEY_COLOR_TRANSPARENT: &str = "$clr.transparent";
+pub const AI_MATKEY_COLOR_REFLECTIVE: &str = "$clr.reflective";
+pub const AI_MATKEY_GLOBAL_BACKGROUND_IMAGE: &str = "?bg.global";
+
+// PBR material keys, written by the glTF, FBX and USD importers among others.
+pub const AI_MATKEY_BASE_COLOR: &str = "$clr.base";
+pub const AI_MATKEY_METALLIC_FACTOR: &str = "$mat.metallicFactor";
+pub const AI_MATKEY_ROUGHNESS_FACTOR: &str = "$mat.roughnessFactor";
+pub const AI_MATKEY_EMISSIVE_INTENSITY: &str = "$mat.emissiveIntensity";
+
+// glTF specific keys. The pbrMetallicRoughness keys are written by the
+// glTF importers of assimp versions preceding the generic PBR keys above.
+pub const AI_MATKEY_GLTF_PBRMETALLICROUGHNESS_BASE_COLOR_FACTOR: &str =
+    "$mat.gltf.pbrMetallicRoughness.baseColorFactor";
+pub const AI_MATKEY_GLTF_PBRMETALLICROUGHNESS_METALLIC_FACTOR: &str =
+    "$mat.gltf.pbrMetallicRoughness.metallicFactor";
+pub const AI_MATKEY_GLTF_PBRMETALLICROUGHNESS_ROUGHNESS_FACTOR: &str =
+    "$mat.gltf.pbrMetallicRoughness.roughnessFactor";
+pub const AI_MATKEY_GLTF_ALPHAMODE: &str = "$mat.gltf.alphaMode";
+pub const AI_MATKEY_GLTF_ALPHACUTOFF: &str = "$mat.gltf.alphaCutoff";
+pub const AI_MATKEY_GLTF_TEXTURE_SCALE_BASE: &str = "$tex.scale";
+pub const AI_MATKEY_GLTF_TEXTURE_STRENGTH_BASE: &str = "$tex.strength";
+
+pub const AI_MATKEY_TEXTURE_BASE: &str = "$tex.file";
+pub const AI_MATKEY_UVWSRC_BASE: &str = "$tex.uvwsrc";
+pub const AI_MATKEY_TEXOP_BASE: &str = "$tex.op";
+pub const AI_MATKEY_MAPPING_BASE: &str = "$tex.mapping";
+pub const AI_MATKEY_TEXBLEND_BASE: &str = "$tex.blend";
+pub const AI_MATKEY_MAPPINGMODE_U_BASE: &str = "$tex.mapmodeu";
+pub const AI_MATKEY_MAPPINGMODE_V_BASE: &str = "$tex.mapmodev";
+pub const AI_MATKEY_TEXMAP_AXIS_BASE: &str = "$tex.mapaxis";
+pub const AI_MATKEY_UVTRANSFORM_BASE: &str = "$tex.uvtrafo";
+pub const AI_MATKEY_TEXFLAGS_BASE: &str = "$tex.flags";
@@ -0,0 +1,250 @@
+#![allow(non_camel_case_types)]
+
+use std::ops::Mul;
+
+use super::{aiQuaternion, aiVector3D};
+use glui::tools::Mat4;
+
+// ---------------------------------------------------------------------------
+/** @brief Represents a row-major 4x4 matrix, use this for homogeneous
+ *   coordinates.
+ *
+ *  There's much confusion about matrix layouts (column vs. row order).
+ *  This is *always* a row-major matrix. Not even with the
+ *  #aiProcess_ConvertToLeftHanded flag, which absolutely does not affect
+ *  matrix order - it just affects the handedness of the coordinate system
+ *  defined thereby.
+ */
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct aiMatrix4x4 {
+    pub a1: f32,
+    pub a2: f32,
+    pub a3: f32,
+    pub a4: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub b3: f32,
+    pub b4: f32,
+    pub c1: f32,
+    pub c2: f32,
+    pub c3: f32,
+    pub c4: f32,
+    pub d1: f32,
+    pub d2: f32,
+    pub d3: f32,
+    pub d4: f32,
+}
+
+impl aiMatrix4x4 {
+    pub fn identity() -> aiMatrix4x4 {
+        aiMatrix4x4::from_rows([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn from_rows(m: [[f32; 4]; 4]) -> aiMatrix4x4 {
+        aiMatrix4x4 {
+            a1: m[0][0],
+            a2: m[0][1],
+            a3: m[0][2],
+            a4: m[0][3],
+            b1: m[1][0],
+            b2: m[1][1],
+            b3: m[1][2],
+            b4: m[1][3],
+            c1: m[2][0],
+            c2: m[2][1],
+            c3: m[2][2],
+            c4: m[2][3],
+            d1: m[3][0],
+            d2: m[3][1],
+            d3: m[3][2],
+            d4: m[3][3],
+        }
+    }
+
+    /// Composes scaling, rotation and translation, applied in this order.
+    pub fn from_trs(
+        translation: aiVector3D,
+        rotation: aiQuaternion,
+        scaling: aiVector3D,
+    ) -> aiMatrix4x4 {
+        let r = rotation.to_matrix();
+        let (t, s) = (translation, scaling);
+        aiMatrix4x4::from_rows([
+            [r.a1 * s.x, r.a2 * s.y, r.a3 * s.z, t.x],
+            [r.b1 * s.x, r.b2 * s.y, r.b3 * s.z, t.y],
+            [r.c1 * s.x, r.c2 * s.y, r.c3 * s.z, t.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Splits an affine matrix into translation, rotation and scaling,
+    /// the inverse of `from_trs`.
+    pub fn decompose(&self) -> (aiVector3D, aiQuaternion, aiVector3D) {
+        let translation = aiVector3D::new(self.a4, self.b4, self.c4);
+
+        let columns = [
+            aiVector3D::new(self.a1, self.b1, self.c1),
+            aiVector3D::new(self.a2, self.b2, self.c2),
+            aiVector3D::new(self.a3, self.b3, self.c3),
+        ];
+        let sign = if self.determinant() < 0.0 { -1.0 } else { 1.0 };
+        let scale = [
+            sign * columns[0].dot(columns[0]).sqrt(),
+            sign * columns[1].dot(columns[1]).sqrt(),
+            sign * columns[2].dot(columns[2]).sqrt(),
+        ];
+
+        let mut m = [[0.0; 4]; 4];
+        m[3][3] = 1.0;
+        for (j, (column, &s)) in columns.iter().zip(scale.iter()).enumerate() {
+            let s = if s != 0.0 { s } else { 1.0 };
+            m[0][j] = column.x / s;
+            m[1][j] = column.y / s;
+            m[2][j] = column.z / s;
+        }
+        let rotation = aiQuaternion::from_rotation_matrix(&aiMatrix4x4::from_rows(m));
+
+        (
+            translation,
+            rotation.normalize(),
+            aiVector3D::new(scale[0], scale[1], scale[2]),
+        )
+    }
+
+    pub fn rows(&self) -> [[f32; 4]; 4] {
+        [
+            [self.a1, self.a2, self.a3, self.a4],
+            [self.b1, self.b2, self.b3, self.b4],
+            [self.c1, self.c2, self.c3, self.c4],
+            [self.d1, self.d2, self.d3, self.d4],
+        ]
+    }
+
+    pub fn transpose(&self) -> aiMatrix4x4 {
+        let m = self.rows();
+        let mut t = [[0.0; 4]; 4];
+        for (i, row) in t.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = m[j][i];
+            }
+        }
+        aiMatrix4x4::from_rows(t)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let m = self.rows();
+        let c = cofactors(&m);
+        m[0][0] * c[0][0] + m[0][1] * c[0][1] + m[0][2] * c[0][2] + m[0][3] * c[0][3]
+    }
+
+    /// The inverse matrix, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<aiMatrix4x4> {
+        let m = self.rows();
+        let c = cofactors(&m);
+        let det = m[0][0] * c[0][0] + m[0][1] * c[0][1] + m[0][2] * c[0][2] + m[0][3] * c[0][3];
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let mut inv = [[0.0; 4]; 4];
+        for (i, row) in inv.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = c[j][i] / det;
+            }
+        }
+        Some(aiMatrix4x4::from_rows(inv))
+    }
+
+    /// Transforms a point, including the translation.
+    pub fn transform_point(&self, p: aiVector3D) -> aiVector3D {
+        aiVector3D::new(
+            self.a1 * p.x + self.a2 * p.y + self.a3 * p.z + self.a4,
+            self.b1 * p.x + self.b2 * p.y + self.b3 * p.z + self.b4,
+            self.c1 * p.x + self.c2 * p.y + self.c3 * p.z + self.c4,
+        )
+    }
+
+    /// Transforms a direction, ignoring the translation.
+    pub fn transform_vector(&self, v: aiVector3D) -> aiVector3D {
+        aiVector3D::new(
+            self.a1 * v.x + self.a2 * v.y + self.a3 * v.z,
+            self.b1 * v.x + self.b2 * v.y + self.b3 * v.z,
+            self.c1 * v.x + self.c2 * v.y + self.c3 * v.z,
+        )
+    }
+
+    /// The matrix transforming normals, the inverse transpose of the upper 3x3 part.
+    pub fn normal_matrix(&self) -> aiMatrix4x4 {
+        let mut m = *self;
+        m.a4 = 0.0;
+        m.b4 = 0.0;
+        m.c4 = 0.0;
+        match m.inverse() {
+            Some(inv) => inv.transpose(),
+            None => m,
+        }
+    }
+}
+
+impl Default for aiMatrix4x4 {
+    fn default() -> aiMatrix4x4 {
+        aiMatrix4x4::identity()
+    }
+}
+
+impl Mul for aiMatrix4x4 {
+    type Output = aiMatrix4x4;
+
+    fn mul(self, rhs: aiMatrix4x4) -> aiMatrix4x4 {
+        let a = self.rows();
+        let b = rhs.rows();
+        let mut m = [[0.0; 4]; 4];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = (0..4).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        aiMatrix4x4::from_rows(m)
+    }
+}
+
+impl From<aiMatrix4x4> for Mat4 {
+    fn from(m: aiMatrix4x4) -> Mat4 {
+        Mat4::from_arr_arr(m.rows())
+    }
+}
+
+/// The cofactor matrix of `m`.
+fn cofactors(m: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let mut c = [[0.0; 4]; 4];
+    for (i, row) in c.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            let mut minor = [[0.0; 3]; 3];
+            let mut mi = 0;
+            for (r, mrow) in m.iter().enumerate() {
+                if r == i {
+                    continue;
+                }
+                let mut mj = 0;
+                for (col, &x) in mrow.iter().enumerate() {
+                    if col == j {
+                        continue;
+                    }
+                    minor[mi][mj] = x;
+                    mj += 1;
+                }
+                mi += 1;
+            }
+            let det = minor[0][0] * (minor[1][1] * minor[2][2] - minor[1][2] * minor[2][1])
+                - minor[0][1] * (minor[1][0] * minor[2][2] - minor[1][2] * minor[2][0])
+                + minor[0][2] * (minor[1][0] * minor[2][1] - minor[1][1] * minor[2][0]);
+            *v = if (i + j) % 2 == 0 { det } else { -det };
+        }
+    }
+    c
+}
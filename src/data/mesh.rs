@@ -1,30 +1,229 @@
 #![allow(dead_code)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
 
 use super::aiFace;
+use super::aiMatrix4x4;
+#[cfg(assimp_5_1)]
+use super::aiNode;
+use super::aiString;
 use super::aiVector3D;
+use glui::tools::{Vec3, Vec4};
 use std::os::raw::c_uint;
 
+// ----------------------------------------------------------------------------------
+/** Represents a color in Red-Green-Blue space including an
+ *   alpha component. Color values range from 0 to 1.
+ */
 #[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct aiColor4D {
-    pub dummy: i32,
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
 }
+
+impl From<aiColor4D> for Vec4 {
+    fn from(c: aiColor4D) -> Vec4 {
+        Vec4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+c_enum! {
+    // ---------------------------------------------------------------------------
+    /** @brief Enumerates the methods of mesh morphing supported by Assimp.
+     */
+    pub enum aiMorphingMethod {
+        /** Morphing method to be determined */
+        Unknown = 0x0,
+
+        /** Interpolation between morph targets */
+        Vertex_Blend = 0x1,
+
+        /** Normalized morphing between morph targets  */
+        Morph_Normalized = 0x2,
+
+        /** Relative morphing between morph targets  */
+        Morph_Relative = 0x3,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Enumerates the types of geometric primitives supported by Assimp,
+// aiMesh::mPrimitiveTypes is a bitwise combination of them.
+
+/** A point primitive.
+ *
+ * This is just a single vertex in the virtual world,
+ * #aiFace contains just one index for such a primitive.
+ */
+pub const aiPrimitiveType_POINT: c_uint = 0x1;
+
+/** A line primitive.
+ *
+ * This is a line defined through a start and an end position.
+ * #aiFace contains exactly two indices for such a primitive.
+ */
+pub const aiPrimitiveType_LINE: c_uint = 0x2;
+
+/** A triangular primitive.
+ *
+ * A triangle consists of three indices.
+ */
+pub const aiPrimitiveType_TRIANGLE: c_uint = 0x4;
+
+/** A higher-level polygon with more than 3 edges.
+ *
+ * A triangle is a polygon, but polygon in this context means
+ * "all polygons that are not triangles". The "Triangulate"-Step
+ * is provided for your convenience, it splits all polygons in
+ * triangles (which are much easier to handle).
+ */
+pub const aiPrimitiveType_POLYGON: c_uint = 0x8;
+
+/**
+ * A flag to determine whether this triangles only mesh is NGON encoded.
+ */
+pub const aiPrimitiveType_NGONEncodingFlag: c_uint = 0x10;
+
+// ---------------------------------------------------------------------------
+/** @brief A single influence of a bone on a vertex.
+ */
 #[repr(C)]
-pub struct aiBone {
-    pub dummy: i32,
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct aiVertexWeight {
+    /** Index of the vertex which is influenced by the bone. */
+    pub mVertexId: c_uint,
+
+    /** The strength of the influence in the range (0...1).
+     *  The influence from all bones at one vertex amounts to 1.
+     */
+    pub mWeight: f32,
 }
+
+// ---------------------------------------------------------------------------
+/** @brief A single bone of a mesh.
+ *
+ *  A bone has a name by which it can be found in the frame hierarchy and by
+ *  which it can be addressed by animations. In addition it has a number of
+ *  influences on vertices, and a matrix relating the mesh position to the
+ *  position of the bone at the time of binding.
+ */
 #[repr(C)]
-pub struct aiString {
-    pub dummy: i32,
+pub struct aiBone {
+    /** The name of the bone. */
+    pub mName: aiString,
+
+    /** The number of vertices affected by this bone.
+     *  The maximum value for this member is #AI_MAX_BONE_WEIGHTS.
+     */
+    pub mNumWeights: c_uint,
+
+    /** The bone armature node - used for skeleton conversion
+     *  you must enable aiProcess_PopulateArmatureData to populate this
+     */
+    #[cfg(assimp_5_1)]
+    pub mArmature: *const aiNode,
+
+    /** The bone node in the scene - used for skeleton conversion
+     *  you must enable aiProcess_PopulateArmatureData to populate this
+     */
+    #[cfg(assimp_5_1)]
+    pub mNode: *const aiNode,
+
+    /** The influence weights of this bone, by vertex index. */
+    pub mWeights: *const aiVertexWeight,
+
+    /** Matrix that transforms from mesh space to bone space in bind pose.
+     *
+     * This matrix describes the position of the mesh
+     * in the local space of this bone when the skeleton was bound.
+     * Thus it can be used directly to determine a desired vertex position,
+     * given the world-space transform of the bone when animated,
+     * and the position of the vertex in mesh space.
+     *
+     * It is sometimes called an inverse-bind matrix,
+     * or inverse bind pose matrix.
+     */
+    pub mOffsetMatrix: aiMatrix4x4,
 }
+// ---------------------------------------------------------------------------
+/** @brief An AnimMesh is an attachment to an #aiMesh stores per-vertex
+ *  animations for a particular frame.
+ *
+ *  You may think of an #aiAnimMesh as a `patch` for the host mesh, which
+ *  replaces only certain vertex data streams at a particular time.
+ *  Each mesh stores n attached attached meshes (#aiMesh::mAnimMeshes).
+ *  The actual relationship between the time line and anim meshes is
+ *  established by #aiMeshAnim, which references singular mesh attachments
+ *  by their ID and binds them to a time offset.
+ */
 #[repr(C)]
 pub struct aiAnimMesh {
-    pub dummy: i32,
+    /**Anim Mesh name */
+    pub mName: aiString,
+
+    /** Replacement for aiMesh::mVertices. If this array is non-nullptr,
+     *  it *must* contain mNumVertices entries. The corresponding
+     *  array in the host mesh must be non-nullptr as well - animation
+     *  meshes may neither add or nor remove vertex components (if
+     *  a replacement array is nullptr and the corresponding source
+     *  array is not, the source data is taken instead)*/
+    pub mVertices: *const aiVector3D,
+
+    /** Replacement for aiMesh::mNormals.  */
+    pub mNormals: *const aiVector3D,
+
+    /** Replacement for aiMesh::mTangents. */
+    pub mTangents: *const aiVector3D,
+
+    /** Replacement for aiMesh::mBitangents. */
+    pub mBitangents: *const aiVector3D,
+
+    /** Replacement for aiMesh::mColors */
+    pub mColors: [*const aiColor4D; AI_MAX_NUMBER_OF_COLOR_SETS],
+
+    /** Replacement for aiMesh::mTextureCoords */
+    pub mTextureCoords: [*const aiVector3D; AI_MAX_NUMBER_OF_TEXTURECOORDS],
+
+    /** The number of vertices in the aiAnimMesh, and thus the length of all
+     * the member arrays.
+     *
+     * This has always the same value as the mNumVertices property in the
+     * corresponding aiMesh. It is duplicated here merely to make the length
+     * of the member arrays accessible even if the aiMesh is not known, e.g.
+     * from language bindings.
+     */
+    pub mNumVertices: c_uint,
+
+    /**
+     * Weight of the AnimMesh.
+     */
+    pub mWeight: f32,
 }
+// ---------------------------------------------------------------------------
+/** An axis-aligned bounding box.
+ */
 #[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct aiAABB {
-    pub dummy: i32,
+    pub mMin: aiVector3D,
+    pub mMax: aiVector3D,
+}
+
+impl aiAABB {
+    /// The minimum and maximum corners.
+    pub fn min_max(&self) -> (aiVector3D, aiVector3D) {
+        (self.mMin, self.mMax)
+    }
+}
+
+impl From<aiAABB> for (Vec3, Vec3) {
+    fn from(aabb: aiAABB) -> (Vec3, Vec3) {
+        aabb.min_max()
+    }
 }
 
 /** @def AI_MAX_FACE_INDICES
@@ -201,7 +400,7 @@ pub struct aiMesh {
      *      partitioning.
      *   - Vertex animations refer to meshes by their names.
      **/
-    pub mName: *const aiString,
+    pub mName: aiString,
 
     /** The number of attachment meshes. Note! Currently only works with Collada loader. */
     pub mNumAnimMeshes: c_uint,
@@ -213,12 +412,21 @@ pub struct aiMesh {
     pub mAnimMeshes: *const *const aiAnimMesh,
 
     /**
-     *  Method of morphing when animeshes are specified.
+     *  Method of morphing when animeshes are specified,
+     *  an aiMorphingMethod value.
      */
     pub mMethod: c_uint,
 
     /**
-     *
+     *  The bounding box, only set with aiProcess_GenBoundingBoxes.
+     */
+    pub mAABB: aiAABB,
+
+    /**
+     *  Vertex UV stream names. Pointer to array of size AI_MAX_NUMBER_OF_TEXTURECOORDS
      */
-    pub mAABB: *const aiAABB,
+    // the last field, gating it later than needed only hides the names and
+    // never shifts the layout of the other fields
+    #[cfg(assimp_5_2)]
+    pub mTextureCoordsNames: *const *const aiString,
 }
@@ -0,0 +1,81 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use std::ffi::c_void;
+use std::os::raw::c_uint;
+
+use super::aiString;
+
+c_enum! {
+    // -------------------------------------------------------------------------------
+    /**
+     * Enum used to distinguish data types
+     */
+    // -------------------------------------------------------------------------------
+    pub enum aiMetadataType {
+        Bool = 0,
+        Int32 = 1,
+        UInt64 = 2,
+        Float = 3,
+        Double = 4,
+        AiString = 5,
+        AiVector3D = 6,
+        AiMetadata = 7,
+        Int64 = 8,
+        UInt32 = 9,
+    }
+}
+
+// -------------------------------------------------------------------------------
+/**
+ * Metadata entry
+ *
+ * The type field uniquely identifies the underlying type of the data field
+ */
+// -------------------------------------------------------------------------------
+#[repr(C)]
+pub struct aiMetadataEntry {
+    /** The type of the data, an aiMetadataType value. */
+    pub mType: c_uint,
+    pub mData: *const c_void,
+}
+
+// -------------------------------------------------------------------------------
+/**
+ * Container for holding metadata.
+ *
+ * Metadata is a key-value store using string keys and values.
+ */
+// -------------------------------------------------------------------------------
+#[repr(C)]
+pub struct aiMetadata {
+    /** Length of the mKeys and mValues arrays, respectively */
+    pub mNumProperties: c_uint,
+
+    /** Arrays of keys, may not be nullptr. Entries in this array may not be nullptr as well. */
+    pub mKeys: *const aiString,
+
+    /** Arrays of values, may not be nullptr. Entries in this array may be nullptr if the
+     * corresponding property key has no assigned value. */
+    pub mValues: *const aiMetadataEntry,
+}
+
+/** Scene metadata holding the name of the importer which loaded the source asset.
+ *  This is always present if the scene was created from an imported asset.
+ */
+pub const AI_METADATA_SOURCE_FORMAT: &str = "SourceAsset_Format";
+
+/** Scene metadata holding the version of the source asset as a string, if available.
+ *  Not all formats add this metadata.
+ */
+pub const AI_METADATA_SOURCE_FORMAT_VERSION: &str = "SourceAsset_FormatVersion";
+
+/** Scene metadata holding the name of the software which generated the source asset, if available.
+ *  Not all formats add this metadata.
+ */
+pub const AI_METADATA_SOURCE_GENERATOR: &str = "SourceAsset_Generator";
+
+/** Scene metadata holding the source asset copyright statement, if available.
+ *  Not all formats add this metadata.
+ */
+pub const AI_METADATA_SOURCE_COPYRIGHT: &str = "SourceAsset_Copyright";
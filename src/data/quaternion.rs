@@ -0,0 +1,137 @@
+#![allow(non_camel_case_types)]
+
+use super::aiMatrix4x4;
+
+// ---------------------------------------------------------------------------
+/** Represents a quaternion in a 4D vector.
+ */
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct aiQuaternion {
+    /** w,x,y,z components of the quaternion */
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl aiQuaternion {
+    pub fn new(w: f32, x: f32, y: f32, z: f32) -> aiQuaternion {
+        aiQuaternion { w, x, y, z }
+    }
+
+    pub fn identity() -> aiQuaternion {
+        aiQuaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// The rotation of the upper 3x3 part of `m`, which must be orthonormal.
+    pub fn from_rotation_matrix(m: &aiMatrix4x4) -> aiQuaternion {
+        let trace = m.a1 + m.b2 + m.c3;
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            aiQuaternion::new(
+                0.25 * s,
+                (m.c2 - m.b3) / s,
+                (m.a3 - m.c1) / s,
+                (m.b1 - m.a2) / s,
+            )
+        } else if m.a1 > m.b2 && m.a1 > m.c3 {
+            let s = (1.0 + m.a1 - m.b2 - m.c3).sqrt() * 2.0;
+            aiQuaternion::new(
+                (m.c2 - m.b3) / s,
+                0.25 * s,
+                (m.a2 + m.b1) / s,
+                (m.c1 + m.a3) / s,
+            )
+        } else if m.b2 > m.c3 {
+            let s = (1.0 + m.b2 - m.a1 - m.c3).sqrt() * 2.0;
+            aiQuaternion::new(
+                (m.a3 - m.c1) / s,
+                (m.a2 + m.b1) / s,
+                0.25 * s,
+                (m.b3 + m.c2) / s,
+            )
+        } else {
+            let s = (1.0 + m.c3 - m.a1 - m.b2).sqrt() * 2.0;
+            aiQuaternion::new(
+                (m.b1 - m.a2) / s,
+                (m.c1 + m.a3) / s,
+                (m.b3 + m.c2) / s,
+                0.25 * s,
+            )
+        }
+    }
+
+    pub fn dot(&self, q: aiQuaternion) -> f32 {
+        self.w * q.w + self.x * q.x + self.y * q.y + self.z * q.z
+    }
+
+    /// The quaternion scaled to unit length, unchanged if its length is zero.
+    pub fn normalize(&self) -> aiQuaternion {
+        let len = self.dot(*self).sqrt();
+        if len > 0.0 {
+            aiQuaternion::new(self.w / len, self.x / len, self.y / len, self.z / len)
+        } else {
+            *self
+        }
+    }
+
+    /// Spherical linear interpolation along the shortest arc, `t` outside
+    /// of [0, 1] extrapolates along the same arc.
+    pub fn slerp(&self, to: aiQuaternion, t: f32) -> aiQuaternion {
+        let mut cos = self.dot(to);
+        let mut to = to;
+        if cos < 0.0 {
+            cos = -cos;
+            to = aiQuaternion::new(-to.w, -to.x, -to.y, -to.z);
+        }
+
+        let (a, b) = if cos > 0.9999 {
+            (1.0 - t, t)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+
+        aiQuaternion::new(
+            a * self.w + b * to.w,
+            a * self.x + b * to.x,
+            a * self.y + b * to.y,
+            a * self.z + b * to.z,
+        )
+        .normalize()
+    }
+
+    /// The rotation matrix of the quaternion, which must be normalized.
+    pub fn to_matrix(&self) -> aiMatrix4x4 {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        aiMatrix4x4::from_rows([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - z * w),
+                2.0 * (x * z + y * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * y + z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - x * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * z - y * w),
+                2.0 * (y * z + x * w),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+impl Default for aiQuaternion {
+    fn default() -> aiQuaternion {
+        aiQuaternion::identity()
+    }
+}
@@ -4,37 +4,119 @@
 use std::ffi::c_void;
 use std::os::raw::c_uint;
 
+use super::anim::aiAnimation;
+use super::camera::aiCamera;
+use super::light::aiLight;
+use super::material::aiMaterial;
 use super::mesh::aiMesh;
+use super::metadata::aiMetadata;
+use super::texture::aiTexture;
+use super::{aiMatrix4x4, aiString};
 
+// -------------------------------------------------------------------------------
+/**
+ * A node in the imported hierarchy.
+ *
+ * Each node has name, a parent node (except for the root node),
+ * a transformation relative to its parent and possibly several child nodes.
+ * Simple file formats don't support hierarchical structures - for these formats
+ * the imported scene does consist of only a single root node without children.
+ */
+// -------------------------------------------------------------------------------
 #[repr(C)]
 pub struct aiNode {
-    dummy: i32,
-}
-#[repr(C)]
-pub struct aiMaterial {
-    dummy: i32,
-}
-#[repr(C)]
-pub struct aiAnimation {
-    dummy: i32,
-}
-#[repr(C)]
-pub struct aiTexture {
-    dummy: i32,
-}
-#[repr(C)]
-pub struct aiCamera {
-    dummy: i32,
-}
-#[repr(C)]
-pub struct aiLight {
-    dummy: i32,
-}
-#[repr(C)]
-pub struct aiMetadata {
-    dummy: i32,
+    /** The name of the node.
+     *
+     * The name might be empty (length of zero) but all nodes which
+     * need to be referenced by either bones or animations are named.
+     * Multiple nodes may have the same name, except for nodes which are referenced
+     * by bones (see #aiBone and #aiMesh::mBones). Their names *must* be unique.
+     *
+     * Cameras and lights reference a specific node by name - if there
+     * are multiple nodes with this name, they are assigned to each of them.
+     * <br>
+     * There are no limitations with regard to the characters contained in
+     * the name string as it is usually taken directly from the source file.
+     */
+    pub mName: aiString,
+
+    /** The transformation relative to the node's parent. */
+    pub mTransformation: aiMatrix4x4,
+
+    /** Parent node. nullptr if this node is the root node. */
+    pub mParent: *const aiNode,
+
+    /** The number of child nodes of this node. */
+    pub mNumChildren: c_uint,
+
+    /** The child nodes of this node. nullptr if mNumChildren is 0. */
+    pub mChildren: *const *const aiNode,
+
+    /** The number of meshes of this node. */
+    pub mNumMeshes: c_uint,
+
+    /** The meshes of this node. Each entry is an index into the
+     * mesh list of the #aiScene.
+     */
+    pub mMeshes: *const c_uint,
+
+    /** Metadata associated with this node or nullptr if there is no metadata.
+     *  Whether any metadata is generated depends on the source file format. See the
+     * @link importer_notes @endlink page for more information on every source file
+     * format. Importers that don't document any metadata don't write any.
+     */
+    pub mMetaData: *const aiMetadata,
 }
 
+/**
+ * Specifies that the scene data structure that was imported is not complete.
+ * This flag bypasses some internal validations and allows the import
+ * of animation skeletons, material libraries or camera animation paths
+ * using Assimp. Most applications won't support such data.
+ */
+pub const AI_SCENE_FLAGS_INCOMPLETE: c_uint = 0x1;
+
+/**
+ * This flag is set by the validation postprocess-step (aiPostProcess_ValidateDS)
+ * if the validation is successful. In a validated scene you can be sure that
+ * any cross references in the data structure (e.g. vertex indices) are valid.
+ */
+pub const AI_SCENE_FLAGS_VALIDATED: c_uint = 0x2;
+
+/**
+ * This flag is set by the validation postprocess-step (aiPostProcess_ValidateDS)
+ * if the validation is successful but some issues have been found.
+ * This can for example mean that a texture that does not exist is referenced
+ * by a material or that the bone weights for a vertex don't sum to 1.0 ... .
+ * In most cases you should still be able to use the import. This flag could
+ * be useful for applications which don't capture Assimp's log output.
+ */
+pub const AI_SCENE_FLAGS_VALIDATION_WARNING: c_uint = 0x4;
+
+/**
+ * This flag is currently only set by the aiProcess_JoinIdenticalVertices step.
+ * It indicates that the vertices of the output meshes aren't in the internal
+ * verbose format anymore. In the verbose format all vertices are unique,
+ * no vertex is ever referenced by more than one face.
+ */
+pub const AI_SCENE_FLAGS_NON_VERBOSE_FORMAT: c_uint = 0x8;
+
+/**
+ * Denotes pure height-map terrain data. Pure terrains usually consist of quads,
+ * sometimes triangles, in a regular grid. The x,y coordinates of all vertex
+ * positions refer to the x,y coordinates on the terrain height map, the z-axis
+ * stores the elevation at a specific point.
+ */
+pub const AI_SCENE_FLAGS_TERRAIN: c_uint = 0x10;
+
+/**
+ * Specifies that the scene data can be shared between structures. For example:
+ * one vertex in few faces. AI_SCENE_FLAGS_NON_VERBOSE_FORMAT can not be
+ * used for this because AI_SCENE_FLAGS_NON_VERBOSE_FORMAT has internal
+ * meaning about postprocessing steps.
+ */
+pub const AI_SCENE_FLAGS_ALLOW_SHARED: c_uint = 0x20;
+
 // -------------------------------------------------------------------------------
 /** The root structure of the imported data.
  *
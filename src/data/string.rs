@@ -0,0 +1,92 @@
+#![allow(non_camel_case_types)]
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::os::raw::c_char;
+use std::slice;
+use std::str::{self, Utf8Error};
+
+/** @def MAXLEN
+ *  Maximal length of a string, including the terminating zero. */
+pub const MAXLEN: usize = 1024;
+
+// ----------------------------------------------------------------------------------
+/** Represents an UTF-8 string, zero byte terminated.
+ *
+ *  The character set of an aiString is explicitly defined to be UTF-8. This Unicode
+ *  transformation was chosen in the belief that most strings in 3d files are limited
+ *  to ASCII, thus the character set needed to be strictly ASCII compatible.
+ *
+ *  Most text file loaders provide proper Unicode input file handling, special unicode
+ *  characters are correctly transcoded to UTF8 and are kept throughout the libraries'
+ *  import pipeline.
+ *
+ *  For most applications, it will be absolutely sufficient to interpret the
+ *  aiString as ASCII data and work with it as one would work with a plain char*.
+ */
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct aiString {
+    /** Binary length of the string excluding the terminal 0. This is NOT the
+     *  logical length of strings containing UTF-8 multi-byte sequences! It's
+     *  the number of bytes from the beginning of the string to its end.*/
+    pub length: u32,
+
+    /** String buffer. Size limit is MAXLEN */
+    pub data: [c_char; MAXLEN],
+}
+
+impl aiString {
+    /// The bytes of the string, without the terminating zero.
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = (self.length as usize).min(MAXLEN - 1);
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const u8, len) }
+    }
+
+    /// The string, or an error if it is not valid UTF-8.
+    pub fn as_str(&self) -> Result<&str, Utf8Error> {
+        str::from_utf8(self.as_bytes())
+    }
+
+    /// The string with invalid UTF-8 sequences replaced.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.as_bytes())
+    }
+}
+
+impl Default for aiString {
+    fn default() -> aiString {
+        aiString {
+            length: 0,
+            data: [0; MAXLEN],
+        }
+    }
+}
+
+/// Copies the string, truncating it to `MAXLEN - 1` bytes.
+impl<'a> From<&'a str> for aiString {
+    fn from(s: &'a str) -> aiString {
+        let mut string = aiString::default();
+        let bytes = &s.as_bytes()[..s.len().min(MAXLEN - 1)];
+        for (dst, &src) in string.data.iter_mut().zip(bytes.iter()) {
+            *dst = src as c_char;
+        }
+        string.length = bytes.len() as u32;
+        string
+    }
+}
+
+impl<'a> TryFrom<&'a aiString> for &'a str {
+    type Error = Utf8Error;
+
+    fn try_from(s: &'a aiString) -> Result<&'a str, Utf8Error> {
+        s.as_str()
+    }
+}
+
+impl Display for aiString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_lossy())
+    }
+}
@@ -0,0 +1,4 @@
+#![allow(non_camel_case_types)]
+extern crate glui;
+
+pub type aiVector2D = glui::tools::Vec2;
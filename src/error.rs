@@ -0,0 +1,97 @@
+use std::error;
+use std::ffi::NulError;
+use std::fmt::{Display, Formatter};
+
+use postprocess::aiPostProcessSteps;
+
+/// Errors reported by the import functions.
+///
+/// Variants produced by assimp carry the text of `aiGetErrorString`, or of
+/// the error logged by assimp for `PostProcessing`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The file could not be opened.
+    FileNotFound(String),
+    /// No importer is able to read the file format.
+    UnsupportedFormat(String),
+    /// The importer failed to read the file.
+    Parse(String),
+    /// A string passed to assimp contains a NUL byte.
+    InteriorNul(NulError),
+    /// The scene has the `AI_SCENE_FLAGS_INCOMPLETE` flag set, see
+    /// `Importer::reject_incomplete`.
+    IncompleteScene,
+    /// No node of the scene has the given name.
+    MissingNode(String),
+    /// A bone of the mesh is not a joint of the skeleton.
+    MissingJoint(String),
+    /// A skeleton has more joints than 16 bit joint indices can address.
+    TooManyJoints(usize),
+    /// A memory buffer is larger than the 32 bit length assimp accepts.
+    BufferTooLarge(usize),
+    /// Assimp failed to allocate a structure, e.g. a property store.
+    AllocationFailed,
+    /// With the `dlopen` feature, no library was loaded by `Library::open` yet.
+    LibraryNotLoaded,
+    /// Applying the post-processing steps failed and the scene was released.
+    PostProcessing {
+        steps: aiPostProcessSteps,
+        message: String,
+    },
+}
+
+impl Error {
+    /// Classifies an error message returned by `aiGetErrorString`.
+    pub(crate) fn from_assimp(msg: String) -> Error {
+        if msg.starts_with("Unable to open file") {
+            Error::FileNotFound(msg)
+        } else if msg.starts_with("No suitable reader found") {
+            Error::UnsupportedFormat(msg)
+        } else {
+            Error::Parse(msg)
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Error::FileNotFound(msg) => write!(f, "file not found: {}", msg),
+            Error::UnsupportedFormat(msg) => write!(f, "unsupported format: {}", msg),
+            Error::Parse(msg) => write!(f, "import failed: {}", msg),
+            Error::InteriorNul(e) => write!(f, "invalid string: {}", e),
+            Error::IncompleteScene => write!(f, "the imported scene is incomplete"),
+            Error::MissingNode(name) => write!(f, "no node named {:?}", name),
+            Error::MissingJoint(name) => write!(f, "no joint named {:?}", name),
+            Error::TooManyJoints(count) => {
+                write!(f, "{} joints do not fit into 16 bit joint indices", count)
+            }
+            Error::BufferTooLarge(len) => {
+                write!(f, "buffer of {} bytes is too large to import", len)
+            }
+            Error::AllocationFailed => write!(f, "assimp failed to allocate memory"),
+            Error::LibraryNotLoaded => write!(
+                f,
+                "the assimp library is not loaded, call Library::open first"
+            ),
+            Error::PostProcessing { steps, message } => {
+                write!(f, "post-processing with {:?} failed: {}", steps, message)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::InteriorNul(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(e: NulError) -> Error {
+        Error::InteriorNul(e)
+    }
+}
@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fs;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::os::raw::{c_char, c_int, c_uint};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Component, Path, PathBuf};
+use std::ptr;
+use std::slice;
+
+#[cfg(feature = "archive")]
+use std::cell::RefCell;
+#[cfg(feature = "archive")]
+use zip;
+
+use data::*;
+
+/// An open file of a `FileSystem`.
+///
+/// Implemented for every `Read + Seek` type, importing never writes.
+pub trait FileStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::ErrorKind::PermissionDenied.into())
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64>;
+
+    /// The current position in the file.
+    fn tell(&mut self) -> io::Result<u64> {
+        self.seek(SeekFrom::Current(0))
+    }
+
+    /// The size of the file in bytes.
+    fn size(&mut self) -> io::Result<u64>;
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: Read + Seek> FileStream for T {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Read::read(self, buf)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        Seek::seek(self, pos)
+    }
+
+    fn size(&mut self) -> io::Result<u64> {
+        let pos = Seek::stream_position(self)?;
+        let end = Seek::seek(self, SeekFrom::End(0))?;
+        Seek::seek(self, SeekFrom::Start(pos))?;
+        Ok(end)
+    }
+}
+
+/// A source of files for the importers, see `Scene::import_from_file_system`.
+///
+/// Assimp opens the imported file and every file it references through
+/// it, so multi-file formats like OBJ with MTL or glTF with separate
+/// buffers can be read from anywhere.
+pub trait FileSystem {
+    /// Opens `path` with an `fopen` style `mode`, e.g. `"rb"`.
+    fn open(&self, path: &str, mode: &str) -> io::Result<Box<dyn FileStream>>;
+
+    /// Called when assimp is done with a file.
+    fn close(&self, file: Box<dyn FileStream>) {
+        drop(file);
+    }
+}
+
+/// Files of a directory on disk, paths are relative to it.
+///
+/// Absolute paths and paths leaving the directory through `..` are rejected,
+/// so a model cannot reference files outside of it.
+#[derive(Clone, Debug)]
+pub struct DirectoryFileSystem {
+    root: PathBuf,
+}
+
+impl DirectoryFileSystem {
+    pub fn new<P: Into<PathBuf>>(root: P) -> DirectoryFileSystem {
+        DirectoryFileSystem { root: root.into() }
+    }
+}
+
+impl FileSystem for DirectoryFileSystem {
+    fn open(&self, path: &str, mode: &str) -> io::Result<Box<dyn FileStream>> {
+        check_read_only(mode)?;
+        let file = fs::File::open(self.root.join(path_in_root(path)?))?;
+        Ok(Box::new(file))
+    }
+}
+
+/// In-memory files by path, `./` prefixes and `\` separators are ignored.
+impl FileSystem for HashMap<String, Vec<u8>> {
+    fn open(&self, path: &str, mode: &str) -> io::Result<Box<dyn FileStream>> {
+        check_read_only(mode)?;
+        let data = self
+            .get(&normalize_path(path))
+            .or_else(|| self.get(path))
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        Ok(Box::new(Cursor::new(data.clone())))
+    }
+}
+
+/// Files of a zip archive, each one is decompressed into memory when opened.
+#[cfg(feature = "archive")]
+pub struct ZipFileSystem<R: Read + Seek> {
+    archive: RefCell<zip::ZipArchive<R>>,
+}
+
+#[cfg(feature = "archive")]
+impl<R: Read + Seek> ZipFileSystem<R> {
+    pub fn new(reader: R) -> zip::result::ZipResult<ZipFileSystem<R>> {
+        Ok(ZipFileSystem {
+            archive: RefCell::new(zip::ZipArchive::new(reader)?),
+        })
+    }
+}
+
+#[cfg(feature = "archive")]
+impl<R: Read + Seek> FileSystem for ZipFileSystem<R> {
+    fn open(&self, path: &str, mode: &str) -> io::Result<Box<dyn FileStream>> {
+        check_read_only(mode)?;
+        let mut archive = self.archive.borrow_mut();
+        let mut entry = archive
+            .by_name(&normalize_path(path))
+            .map_err(io::Error::from)?;
+        let mut data = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut data)?;
+        Ok(Box::new(Cursor::new(data)))
+    }
+}
+
+fn check_read_only(mode: &str) -> io::Result<()> {
+    if mode.contains(['w', 'a', '+']) {
+        Err(io::ErrorKind::PermissionDenied.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Resolves `.` and `..` components and uses `/` as separator.
+fn normalize_path(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split(['/', '\\']) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// Resolves `.` and `..` components of a relative path, failing for absolute
+/// paths and paths leaving the root.
+fn path_in_root(path: &str) -> io::Result<PathBuf> {
+    let outside = || io::Error::new(io::ErrorKind::PermissionDenied, "path leaves the root");
+    if path.starts_with(['/', '\\']) || Path::new(path).is_absolute() {
+        return Err(outside());
+    }
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split(['/', '\\']) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop().ok_or_else(outside)?;
+            }
+            _ => parts.push(part),
+        }
+    }
+    let relative: PathBuf = parts.iter().collect();
+    // drive prefixes like `C:` replace the root when joined
+    if relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+    {
+        Ok(relative)
+    } else {
+        Err(outside())
+    }
+}
+
+/// Bridges a `FileSystem` to the `aiFileIO` callbacks for the duration of an import.
+///
+/// Boxed so the callbacks can find the file system through a stable pointer.
+pub(crate) struct FileIo<'a> {
+    io: aiFileIO,
+    fs: &'a dyn FileSystem,
+}
+
+impl<'a> FileIo<'a> {
+    pub(crate) fn new(fs: &'a dyn FileSystem) -> Box<FileIo<'a>> {
+        let mut io = Box::new(FileIo {
+            io: aiFileIO {
+                OpenProc: Some(open_proc),
+                CloseProc: Some(close_proc),
+                UserData: ptr::null_mut(),
+            },
+            fs,
+        });
+        io.io.UserData = &mut io.fs as *mut &dyn FileSystem as aiUserData;
+        io
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut aiFileIO {
+        &mut self.io
+    }
+}
+
+unsafe fn file_system<'a>(io: *mut aiFileIO) -> &'a dyn FileSystem {
+    *((*io).UserData as *const &dyn FileSystem)
+}
+
+unsafe fn stream<'a>(file: *mut aiFile) -> &'a mut Box<dyn FileStream> {
+    &mut *((*file).UserData as *mut Box<dyn FileStream>)
+}
+
+/// Runs the body of a callback, panics must not unwind into assimp.
+fn no_unwind<T, F: FnOnce() -> T>(fallback: T, body: F) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(fallback)
+}
+
+unsafe extern "C" fn open_proc(
+    io: *mut aiFileIO,
+    path: *const c_char,
+    mode: *const c_char,
+) -> *mut aiFile {
+    no_unwind(ptr::null_mut(), || {
+        let path = CStr::from_ptr(path).to_string_lossy();
+        let mode = CStr::from_ptr(mode).to_string_lossy();
+        match file_system(io).open(&path, &mode) {
+            Ok(stream) => Box::into_raw(Box::new(aiFile {
+                ReadProc: Some(read_proc),
+                WriteProc: Some(write_proc),
+                TellProc: Some(tell_proc),
+                FileSizeProc: Some(size_proc),
+                SeekProc: Some(seek_proc),
+                FlushProc: Some(flush_proc),
+                UserData: Box::into_raw(Box::new(stream)) as aiUserData,
+            })),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+unsafe extern "C" fn close_proc(io: *mut aiFileIO, file: *mut aiFile) {
+    if file.is_null() {
+        return;
+    }
+    no_unwind((), || {
+        let file = Box::from_raw(file);
+        let stream = Box::from_raw(file.UserData as *mut Box<dyn FileStream>);
+        file_system(io).close(*stream);
+    })
+}
+
+unsafe extern "C" fn read_proc(
+    file: *mut aiFile,
+    buffer: *mut c_char,
+    size: usize,
+    count: usize,
+) -> usize {
+    let len = match size.checked_mul(count) {
+        Some(len) if len > 0 => len,
+        _ => return 0,
+    };
+    no_unwind(0, || {
+        let buf = slice::from_raw_parts_mut(buffer as *mut u8, len);
+        let stream = stream(file);
+        let mut done = 0;
+        while done < buf.len() {
+            match stream.read(&mut buf[done..]) {
+                Ok(0) => break,
+                Ok(n) => done += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => break,
+            }
+        }
+        done / size
+    })
+}
+
+unsafe extern "C" fn write_proc(
+    file: *mut aiFile,
+    buffer: *const c_char,
+    size: usize,
+    count: usize,
+) -> usize {
+    let len = match size.checked_mul(count) {
+        Some(len) if len > 0 => len,
+        _ => return 0,
+    };
+    no_unwind(0, || {
+        let buf = slice::from_raw_parts(buffer as *const u8, len);
+        let stream = stream(file);
+        let mut done = 0;
+        while done < buf.len() {
+            match stream.write(&buf[done..]) {
+                Ok(0) => break,
+                Ok(n) => done += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => break,
+            }
+        }
+        done / size
+    })
+}
+
+unsafe extern "C" fn tell_proc(file: *mut aiFile) -> usize {
+    no_unwind(0, || stream(file).tell().map_or(0, |pos| pos as usize))
+}
+
+unsafe extern "C" fn size_proc(file: *mut aiFile) -> usize {
+    no_unwind(0, || stream(file).size().map_or(0, |size| size as usize))
+}
+
+unsafe extern "C" fn seek_proc(file: *mut aiFile, offset: usize, origin: c_uint) -> c_int {
+    // relative offsets are passed as wrapped around unsigned values
+    let pos = match aiOrigin::from_raw(origin) {
+        Some(aiOrigin::Set) => SeekFrom::Start(offset as u64),
+        Some(aiOrigin::Cur) => SeekFrom::Current(offset as isize as i64),
+        Some(aiOrigin::End) => SeekFrom::End(offset as isize as i64),
+        None => return aiReturn::Failure.into(),
+    };
+    no_unwind(aiReturn::Failure.into(), || match stream(file).seek(pos) {
+        Ok(_) => aiReturn::Success.into(),
+        Err(_) => aiReturn::Failure.into(),
+    })
+}
+
+unsafe extern "C" fn flush_proc(file: *mut aiFile) {
+    no_unwind((), || {
+        let _ = stream(file).flush();
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    struct Panicking;
+
+    impl FileSystem for Panicking {
+        fn open(&self, _path: &str, _mode: &str) -> io::Result<Box<dyn FileStream>> {
+            panic!("open failed")
+        }
+    }
+
+    fn open(io: &mut FileIo, path: &str) -> *mut aiFile {
+        let path = CString::new(path).unwrap();
+        let mode = CString::new("rb").unwrap();
+        unsafe { open_proc(io.as_mut_ptr(), path.as_ptr(), mode.as_ptr()) }
+    }
+
+    #[test]
+    fn reads_files_of_the_file_system() {
+        let mut files = HashMap::new();
+        files.insert("dir/a.txt".to_string(), b"hello".to_vec());
+        let mut io = FileIo::new(&files);
+
+        assert!(open(&mut io, "missing.txt").is_null());
+        let file = open(&mut io, "./dir\\a.txt");
+        assert!(!file.is_null());
+        let mut buf = [0u8; 8];
+        unsafe {
+            assert_eq!(size_proc(file), 5);
+            assert_eq!(read_proc(file, buf.as_mut_ptr() as *mut c_char, 2, 4), 2);
+            assert_eq!(&buf[..5], b"hello");
+            assert_eq!(tell_proc(file), 5);
+            close_proc(io.as_mut_ptr(), file);
+        }
+    }
+
+    #[test]
+    fn overflowing_reads_read_nothing() {
+        let mut files = HashMap::new();
+        files.insert("a".to_string(), b"hello".to_vec());
+        let mut io = FileIo::new(&files);
+        let file = open(&mut io, "a");
+        let mut buf = [0u8; 8];
+        unsafe {
+            let buf = buf.as_mut_ptr() as *mut c_char;
+            assert_eq!(read_proc(file, buf, usize::MAX, 2), 0);
+            assert_eq!(tell_proc(file), 0);
+            close_proc(io.as_mut_ptr(), file);
+        }
+    }
+
+    #[test]
+    fn directory_paths_stay_inside_the_root() {
+        assert_eq!(
+            path_in_root("./a\\b/../c.mtl").unwrap(),
+            ["a", "c.mtl"].iter().collect::<PathBuf>()
+        );
+        for path in &["../a", "a/../../b", "/etc/passwd", "\\a"] {
+            let err = path_in_root(path).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        }
+
+        let dir = DirectoryFileSystem::new(env!("CARGO_MANIFEST_DIR"));
+        assert!(dir.open("./cube.obj", "rb").is_ok());
+        assert!(dir.open("../cube.obj", "rb").is_err());
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn reads_files_of_zip_archives() {
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("dir/a.txt", Default::default()).unwrap();
+        writer.write_all(b"hello").unwrap();
+        let archive = writer.finish().unwrap().into_inner();
+        let zip = ZipFileSystem::new(Cursor::new(archive)).unwrap();
+
+        let mut data = Vec::new();
+        let mut file = zip.open("./dir\\a.txt", "rb").unwrap();
+        let mut buf = [0u8; 8];
+        loop {
+            match file.read(&mut buf).unwrap() {
+                0 => break,
+                n => data.extend_from_slice(&buf[..n]),
+            }
+        }
+        assert_eq!(data, b"hello");
+        assert!(zip.open("dir/b.txt", "rb").is_err());
+    }
+
+    #[test]
+    fn panics_do_not_unwind_into_assimp() {
+        let mut io = FileIo::new(&Panicking);
+        assert!(open(&mut io, "a").is_null());
+    }
+}
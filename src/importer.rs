@@ -0,0 +1,256 @@
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::{Mutex, MutexGuard, Once, PoisonError};
+use std::thread::{self, ThreadId};
+
+use cimport::{aiImportFileExWithProperties, aiImportFileFromMemoryWithProperties};
+use data::*;
+use error::Error;
+use filesystem::FileSystem;
+use options::{ImportOptions, PropertyValue};
+use postprocess::aiPostProcessSteps;
+use raw_assimp;
+use scene::Scene;
+
+pub(crate) type LogSink<'a> = Mutex<Box<dyn FnMut(&str) + Send + 'a>>;
+
+/// The sinks of the running imports by address, with the thread importing.
+static SINKS: Mutex<Vec<(ThreadId, usize)>> = Mutex::new(Vec::new());
+
+/// Attaches the stream forwarding to `SINKS`.
+static STREAM: Once = Once::new();
+
+/// Builder for imports, bundling the post-processing steps, importer
+/// settings, file system and log output.
+///
+/// ```ignore
+/// let scene = Importer::new()
+///     .steps(aiPostProcessSteps::Triangulate | aiPostProcessSteps::GenSmoothNormals)
+///     .property(AI_CONFIG_PP_GSN_MAX_SMOOTHING_ANGLE, PropertyValue::Float(60.0))
+///     .log_to(|msg| eprintln!("{}", msg))
+///     .read_file("cube.obj")?;
+/// ```
+pub struct Importer<'a> {
+    steps: aiPostProcessSteps,
+    options: ImportOptions,
+    fs: Option<&'a dyn FileSystem>,
+    log: Option<LogSink<'a>>,
+    reject_incomplete: bool,
+}
+
+impl<'a> Importer<'a> {
+    /// An importer running `default_steps`, reading from disk without logging.
+    pub fn new() -> Importer<'a> {
+        Importer {
+            steps: Importer::default_steps(),
+            options: ImportOptions::new(),
+            fs: None,
+            log: None,
+            reject_incomplete: false,
+        }
+    }
+
+    /// Triangulates, generates smooth normals and uv coordinates and flips
+    /// the uvs to a top-left origin.
+    pub fn default_steps() -> aiPostProcessSteps {
+        aiPostProcessSteps::Triangulate
+            | aiPostProcessSteps::GenSmoothNormals
+            | aiPostProcessSteps::GenUVCoords
+            | aiPostProcessSteps::FlipUVs
+    }
+
+    /// Replaces the post-processing steps.
+    pub fn steps(mut self, steps: aiPostProcessSteps) -> Importer<'a> {
+        self.steps = steps;
+        self
+    }
+
+    /// Sets an importer property, see the `AI_CONFIG_*` keys.
+    pub fn property(mut self, key: &str, value: PropertyValue) -> Importer<'a> {
+        self.options = self.options.property(key, value);
+        self
+    }
+
+    /// Replaces every importer property.
+    pub fn options(mut self, options: ImportOptions) -> Importer<'a> {
+        self.options = options;
+        self
+    }
+
+    /// Opens the file and the files it references through `fs` instead of the disk.
+    pub fn file_system(mut self, fs: &'a dyn FileSystem) -> Importer<'a> {
+        self.fs = Some(fs);
+        self
+    }
+
+    /// Passes the log messages of assimp to `log`, one message per call.
+    ///
+    /// Only the messages of the importing thread are passed, not those of
+    /// imports running on other threads at the same time. `log` must not
+    /// import itself. The first import with a log attaches one log stream to
+    /// assimp for the rest of the process.
+    pub fn log_to<F>(mut self, log: F) -> Importer<'a>
+    where
+        F: FnMut(&str) + Send + 'a,
+    {
+        self.log = Some(Mutex::new(Box::new(log)));
+        self
+    }
+
+    /// Fails with `Error::IncompleteScene` instead of returning scenes with the
+    /// `AI_SCENE_FLAGS_INCOMPLETE` flag, like animation-only files.
+    pub fn reject_incomplete(mut self, reject: bool) -> Importer<'a> {
+        self.reject_incomplete = reject;
+        self
+    }
+
+    /// Imports the given file.
+    pub fn read_file(&self, file: &str) -> Result<Scene, Error> {
+        let store = self.options.to_property_store()?;
+        let _log = self.log.as_ref().map(LogGuard::attach);
+        aiImportFileExWithProperties(file, self.steps, self.fs, store.raw())
+            .map(Scene::from_import)
+            .and_then(|scene| self.check_complete(scene))
+    }
+
+    /// Imports a file from a memory buffer, see `Scene::import_from_memory`.
+    ///
+    /// The file system is not used, files referenced by the data are not imported.
+    pub fn read_memory(&self, bytes: &[u8], format_hint: &str) -> Result<Scene, Error> {
+        let store = self.options.to_property_store()?;
+        let _log = self.log.as_ref().map(LogGuard::attach);
+        aiImportFileFromMemoryWithProperties(bytes, self.steps, format_hint, store.raw())
+            .map(Scene::from_import)
+            .and_then(|scene| self.check_complete(scene))
+    }
+
+    fn check_complete(&self, scene: Scene) -> Result<Scene, Error> {
+        if self.reject_incomplete && scene.is_incomplete() {
+            Err(Error::IncompleteScene)
+        } else {
+            Ok(scene)
+        }
+    }
+}
+
+impl<'a> Default for Importer<'a> {
+    fn default() -> Importer<'a> {
+        Importer::new()
+    }
+}
+
+/// Registers a sink for the messages logged on the current thread for the
+/// lifetime of the guard.
+pub(crate) struct LogGuard<'s> {
+    thread: ThreadId,
+    sink: usize,
+    marker: PhantomData<&'s ()>,
+}
+
+impl<'s> LogGuard<'s> {
+    pub(crate) fn attach<'a: 's>(sink: &'s LogSink<'a>) -> LogGuard<'s> {
+        // assimp creates its global logger when a stream is attached and kills it
+        // when the last one is detached, even while other threads log through it,
+        // so a single stream stays attached and forwards to the registered sinks
+        STREAM.call_once(|| {
+            let stream = aiLogStream {
+                callback: Some(log_callback),
+                user: ptr::null_mut(),
+            };
+            unsafe { raw_assimp::aiAttachLogStream(&stream) };
+        });
+        LogGuard::register(sink)
+    }
+
+    fn register<'a: 's>(sink: &'s LogSink<'a>) -> LogGuard<'s> {
+        let guard = LogGuard {
+            thread: thread::current().id(),
+            sink: sink as *const LogSink as usize,
+            marker: PhantomData,
+        };
+        sinks().push((guard.thread, guard.sink));
+        guard
+    }
+}
+
+impl<'s> Drop for LogGuard<'s> {
+    fn drop(&mut self) {
+        let mut sinks = sinks();
+        if let Some(i) = sinks.iter().position(|&e| e == (self.thread, self.sink)) {
+            sinks.remove(i);
+        }
+    }
+}
+
+fn sinks() -> MutexGuard<'static, Vec<(ThreadId, usize)>> {
+    SINKS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+unsafe extern "C" fn log_callback(message: *const c_char, _user: *mut c_char) {
+    let message = CStr::from_ptr(message).to_string_lossy();
+    let thread = thread::current().id();
+    // holding the lock keeps the sinks alive, their guards unregister them first
+    for &(_, sink) in sinks().iter().filter(|&&(t, _)| t == thread) {
+        // panics must not unwind into assimp, the sink stays usable afterwards
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            let sink = &*(sink as *const LogSink);
+            let mut log = sink.lock().unwrap_or_else(PoisonError::into_inner);
+            log(message.trim_end());
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    fn log(messages: &[&str]) {
+        for msg in messages {
+            let msg = CString::new(*msg).unwrap();
+            unsafe { log_callback(msg.as_ptr(), ptr::null_mut()) };
+        }
+    }
+
+    #[test]
+    fn log_sinks_survive_panics() {
+        let mut messages = Vec::new();
+        {
+            let sink: LogSink = Mutex::new(Box::new(|msg: &str| {
+                if msg == "panic" {
+                    panic!("log sink failed");
+                }
+                messages.push(msg.to_string());
+            }));
+            let _guard = LogGuard::register(&sink);
+            log(&["panic", "Info,  T0: loaded\n"]);
+            assert!(sink.is_poisoned());
+        }
+        assert_eq!(messages, ["Info,  T0: loaded"]);
+    }
+
+    #[test]
+    fn log_sinks_only_receive_their_threads_messages() {
+        let mut mine = Vec::new();
+        let mut theirs = Vec::new();
+        {
+            let sink: LogSink = Mutex::new(Box::new(|msg: &str| mine.push(msg.to_string())));
+            let _guard = LogGuard::register(&sink);
+            thread::scope(|scope| {
+                scope.spawn(|| {
+                    let sink: LogSink =
+                        Mutex::new(Box::new(|msg: &str| theirs.push(msg.to_string())));
+                    let _guard = LogGuard::register(&sink);
+                    log(&["theirs"]);
+                });
+            });
+            log(&["mine"]);
+        }
+        log(&["unregistered"]);
+        assert_eq!(mine, ["mine"]);
+        assert_eq!(theirs, ["theirs"]);
+    }
+}
@@ -1,9 +1,67 @@
 extern crate glui;
+#[cfg(feature = "decode")]
+extern crate jpeg_decoder;
+#[cfg(feature = "dlopen")]
+extern crate libloading;
+#[cfg(feature = "decode")]
+extern crate png;
+#[cfg(feature = "archive")]
+extern crate zip;
 
+mod animation;
+mod bone;
+mod camera;
 mod cimport;
 mod data;
+mod error;
+mod filesystem;
+mod importer;
+#[cfg(feature = "dlopen")]
+mod library;
+mod light;
+mod material;
+mod mesh;
+mod metadata;
+mod morph;
+mod node;
+mod options;
+mod pbr;
 mod postprocess;
+mod raw_assimp;
+mod sampler;
+mod scene;
+mod skeleton;
+mod skinning;
+#[cfg(test)]
+mod test_util;
+mod texture;
+mod util;
 
+pub use animation::{Animation, NodeTrack, DEFAULT_TICKS_PER_SECOND};
+pub use bone::Bone;
+pub use camera::Camera;
 pub use cimport::*;
 pub use data::*;
+pub use error::Error;
+#[cfg(feature = "archive")]
+pub use filesystem::ZipFileSystem;
+pub use filesystem::{DirectoryFileSystem, FileStream, FileSystem};
+pub use importer::Importer;
+#[cfg(feature = "dlopen")]
+pub use library::{Library, LoadError};
+pub use light::{Attenuation, Light, LightColor};
+pub use material::*;
+pub use mesh::{Faces, MeshView};
+pub use metadata::{Metadata, MetadataValue};
+pub use morph::{blend_morph_targets, MorphAnim, MorphTarget, MorphedVertices};
+pub use node::{BreadthFirst, DepthFirst, Node};
+pub use options::{ImportOptions, PropertyStore, PropertyValue};
+pub use pbr::{AlphaMode, PbrMaterial};
 pub use postprocess::*;
+pub use sampler::{sample_rotation, sample_track, sample_vector, Pose, Sampler, Transform};
+pub use scene::Scene;
+pub use skeleton::{Joint, Skeleton, SkeletonBuilder, SkinWeights};
+pub use skinning::{skin_mesh, SkinnedVertices};
+#[cfg(feature = "decode")]
+pub use texture::DecodeError;
+pub use texture::{RgbaImage, Texture, TextureData};
@@ -0,0 +1,210 @@
+#![allow(non_snake_case)]
+
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use libloading;
+
+use data::*;
+use error;
+use filesystem::FileSystem;
+use glui::tools::mesh::Mesh;
+use postprocess::aiPostProcessSteps;
+use raw_assimp::Api;
+
+/// File names tried by `Library::open_default`, in order.
+#[cfg(target_os = "windows")]
+const DEFAULT_NAMES: &[&str] = &[
+    "assimp.dll",
+    "assimp-vc143-mt.dll",
+    "assimp-vc142-mt.dll",
+    "assimp-vc141-mt.dll",
+];
+#[cfg(target_os = "macos")]
+const DEFAULT_NAMES: &[&str] = &["libassimp.dylib", "libassimp.5.dylib"];
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const DEFAULT_NAMES: &[&str] = &["libassimp.so", "libassimp.so.5"];
+
+struct Loaded {
+    api: Api,
+    path: PathBuf,
+    _lib: libloading::Library,
+}
+
+static LOADED: OnceLock<Loaded> = OnceLock::new();
+
+/// Whether a library was opened, so the functions of `api` can be called.
+pub(crate) fn is_loaded() -> bool {
+    LOADED.get().is_some()
+}
+
+pub(crate) fn api() -> &'static Api {
+    match LOADED.get() {
+        Some(loaded) => &loaded.api,
+        None => panic!("the assimp library is not loaded, call Library::open first"),
+    }
+}
+
+/// Error returned when the assimp shared object cannot be loaded.
+#[derive(Debug)]
+pub struct LoadError {
+    path: PathBuf,
+    source: libloading::Error,
+}
+
+impl LoadError {
+    /// The path (or file name) that was tried last.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "assimp not installed: failed to load {}: {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Handle to an assimp shared object loaded at runtime.
+///
+/// Only available with the `dlopen` feature. The library is loaded once and
+/// stays loaded for the rest of the process, every further `open` returns a
+/// handle to the library loaded first. The free functions of this crate can
+/// be used once a library was opened, before that the import functions fail
+/// with `Error::LibraryNotLoaded`.
+#[derive(Copy, Clone)]
+pub struct Library {
+    loaded: &'static Loaded,
+}
+
+impl Library {
+    /// Loads assimp from the shared object at `path` and resolves the C API.
+    pub fn open<P: AsRef<OsStr>>(path: P) -> Result<Library, LoadError> {
+        if let Some(loaded) = LOADED.get() {
+            return Ok(Library { loaded });
+        }
+
+        let path = path.as_ref();
+        let load = || unsafe {
+            let lib = libloading::Library::new(path)?;
+            let api = Api::load(&lib)?;
+            Ok(Loaded {
+                api,
+                path: PathBuf::from(path),
+                _lib: lib,
+            })
+        };
+        let loaded = load().map_err(|source| LoadError {
+            path: PathBuf::from(path),
+            source,
+        })?;
+
+        let _ = LOADED.set(loaded);
+        Ok(Library {
+            loaded: LOADED.get().unwrap(),
+        })
+    }
+
+    /// Loads assimp by the platform's usual library file names.
+    pub fn open_default() -> Result<Library, LoadError> {
+        let mut last_err = None;
+        for name in DEFAULT_NAMES {
+            match Library::open(name) {
+                Ok(lib) => return Ok(lib),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap())
+    }
+
+    /// Path the library was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.loaded.path
+    }
+
+    /// See `cimport::aiImportFile`.
+    pub fn aiImportFile(
+        &self,
+        pFile: &str,
+        pFlags: aiPostProcessSteps,
+    ) -> Result<*const aiScene, error::Error> {
+        ::cimport::aiImportFile(pFile, pFlags)
+    }
+
+    /// See `cimport::aiImportFileEx`.
+    pub fn aiImportFileEx(
+        &self,
+        pFile: &str,
+        pFlags: aiPostProcessSteps,
+        pFS: &dyn FileSystem,
+    ) -> Result<*const aiScene, error::Error> {
+        ::cimport::aiImportFileEx(pFile, pFlags, pFS)
+    }
+
+    /// See `cimport::aiImportFileExWithProperties`.
+    pub fn aiImportFileExWithProperties(
+        &self,
+        pFile: &str,
+        pFlags: aiPostProcessSteps,
+        pFS: Option<&dyn FileSystem>,
+        pProps: &aiPropertyStore,
+    ) -> Result<*const aiScene, error::Error> {
+        ::cimport::aiImportFileExWithProperties(pFile, pFlags, pFS, pProps)
+    }
+
+    /// See `cimport::aiImportFileFromMemory`.
+    pub fn aiImportFileFromMemory(
+        &self,
+        pBuffer: &[u8],
+        pFlags: aiPostProcessSteps,
+        pHint: &str,
+    ) -> Result<*const aiScene, error::Error> {
+        ::cimport::aiImportFileFromMemory(pBuffer, pFlags, pHint)
+    }
+
+    /// See `cimport::aiApplyPostProcessing`.
+    ///
+    /// # Safety
+    /// The requirements of `cimport::aiApplyPostProcessing` apply.
+    pub unsafe fn aiApplyPostProcessing(
+        &self,
+        pScene: *const aiScene,
+        pFlags: aiPostProcessSteps,
+    ) -> Result<*const aiScene, error::Error> {
+        ::cimport::aiApplyPostProcessing(pScene, pFlags)
+    }
+
+    /// See `cimport::aiReleaseImport`.
+    pub fn aiReleaseImport(&self, pScene: *const aiScene) {
+        ::cimport::aiReleaseImport(pScene)
+    }
+
+    /// See `cimport::aiGetErrorString`.
+    pub fn aiGetErrorString(&self) -> String {
+        ::cimport::aiGetErrorString()
+    }
+
+    /// See `cimport::aiImportFileToMesh`.
+    pub fn aiImportFileToMesh(&self, file: &str) -> Result<Mesh, error::Error> {
+        ::cimport::aiImportFileToMesh(file)
+    }
+
+    /// See `cimport::aiImportFileToMeshes`.
+    pub fn aiImportFileToMeshes(&self, file: &str) -> Result<Vec<Mesh>, error::Error> {
+        ::cimport::aiImportFileToMeshes(file)
+    }
+}
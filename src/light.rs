@@ -0,0 +1,162 @@
+use data::*;
+use scene::Scene;
+use util::normalize;
+
+/// The colors of a light source, multiplied with the matching material colors.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LightColor {
+    pub diffuse: aiColor3D,
+    pub specular: aiColor3D,
+    pub ambient: aiColor3D,
+}
+
+/// Distance attenuation factors of a light, see `at`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Attenuation {
+    pub constant: f32,
+    pub linear: f32,
+    pub quadratic: f32,
+}
+
+impl Attenuation {
+    /// The attenuation at `distance`, `1 / (constant + linear * d + quadratic * d * d)`.
+    pub fn at(&self, distance: f32) -> f32 {
+        1.0 / (self.constant + self.linear * distance + self.quadratic * distance * distance)
+    }
+}
+
+/// A light source with its position and directions in world space.
+///
+/// Directions are normalized, cone angles are in radians.
+#[derive(Clone, Debug)]
+pub enum Light {
+    Directional {
+        name: String,
+        direction: aiVector3D,
+        color: LightColor,
+    },
+    Point {
+        name: String,
+        position: aiVector3D,
+        attenuation: Attenuation,
+        color: LightColor,
+    },
+    Spot {
+        name: String,
+        position: aiVector3D,
+        direction: aiVector3D,
+        attenuation: Attenuation,
+        inner_cone: f32,
+        outer_cone: f32,
+        color: LightColor,
+    },
+    Ambient {
+        name: String,
+        color: LightColor,
+    },
+    Area {
+        name: String,
+        position: aiVector3D,
+        direction: aiVector3D,
+        up: aiVector3D,
+        size: aiVector2D,
+        color: LightColor,
+    },
+}
+
+impl Light {
+    /// Resolves `light` with the world transformation of its node in `scene`.
+    ///
+    /// Identity is used if there is no node with the light's name, `None` is
+    /// returned for undefined light types.
+    pub fn from_scene(light: &aiLight, scene: &Scene) -> Option<Light> {
+        let name = light.mName.to_string_lossy();
+        let world = scene
+            .find_node(&name)
+            .map_or_else(aiMatrix4x4::identity, |node| node.world_transformation());
+        Light::from_raw_at(light, &world)
+    }
+
+    /// Resolves `light` for a light node placed at `world`.
+    pub fn from_raw_at(light: &aiLight, world: &aiMatrix4x4) -> Option<Light> {
+        let name = light.mName.to_string_lossy().into_owned();
+        let position = world.transform_point(light.mPosition);
+        let direction = normalize(world.transform_vector(light.mDirection));
+        let up = normalize(world.transform_vector(light.mUp));
+        let attenuation = Attenuation {
+            constant: light.mAttenuationConstant,
+            linear: light.mAttenuationLinear,
+            quadratic: light.mAttenuationQuadratic,
+        };
+        let color = LightColor {
+            diffuse: light.mColorDiffuse,
+            specular: light.mColorSpecular,
+            ambient: light.mColorAmbient,
+        };
+
+        let light = match aiLightSourceType::from_raw(light.mType)? {
+            aiLightSourceType::Undefined => return None,
+            aiLightSourceType::Directional => Light::Directional {
+                name,
+                direction,
+                color,
+            },
+            aiLightSourceType::Point => Light::Point {
+                name,
+                position,
+                attenuation,
+                color,
+            },
+            aiLightSourceType::Spot => Light::Spot {
+                name,
+                position,
+                direction,
+                attenuation,
+                inner_cone: light.mAngleInnerCone,
+                outer_cone: light.mAngleOuterCone,
+                color,
+            },
+            aiLightSourceType::Ambient => Light::Ambient { name, color },
+            aiLightSourceType::Area => Light::Area {
+                name,
+                position,
+                direction,
+                up,
+                size: light.mSize,
+                color,
+            },
+        };
+        Some(light)
+    }
+
+    /// The name of the light, which is also the name of its node.
+    pub fn name(&self) -> &str {
+        match self {
+            Light::Directional { name, .. }
+            | Light::Point { name, .. }
+            | Light::Spot { name, .. }
+            | Light::Ambient { name, .. }
+            | Light::Area { name, .. } => name,
+        }
+    }
+
+    pub fn color(&self) -> &LightColor {
+        match self {
+            Light::Directional { color, .. }
+            | Light::Point { color, .. }
+            | Light::Spot { color, .. }
+            | Light::Ambient { color, .. }
+            | Light::Area { color, .. } => color,
+        }
+    }
+
+    pub fn light_type(&self) -> aiLightSourceType {
+        match self {
+            Light::Directional { .. } => aiLightSourceType::Directional,
+            Light::Point { .. } => aiLightSourceType::Point,
+            Light::Spot { .. } => aiLightSourceType::Spot,
+            Light::Ambient { .. } => aiLightSourceType::Ambient,
+            Light::Area { .. } => aiLightSourceType::Area,
+        }
+    }
+}
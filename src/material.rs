@@ -0,0 +1,412 @@
+#![allow(non_snake_case)]
+
+use std::ffi::CString;
+use std::os::raw::{c_int, c_uint};
+use std::path::{Path, PathBuf};
+use std::ptr;
+
+use data::*;
+use pbr::PbrMaterial;
+use raw_assimp;
+use util::raw_ptr_slice;
+
+// ---------------------------------------------------------------------------
+/** @brief Retrieve a color value from the material property table
+ *
+ * @param pMat Pointer to the input material. May not be NULL
+ * @param pKey Key to search for. One of the AI_MATKEY_XXX constants.
+ * @param type Specifies the type of the texture to be retrieved, 0 for
+ *   non-texture properties.
+ * @param index Index of the texture to be retrieved, 0 for non-texture
+ *   properties.
+ * @return The color or None if the key has not been found
+ */
+pub fn aiGetMaterialColor(
+    pMat: &aiMaterial,
+    pKey: &str,
+    type_: c_uint,
+    index: c_uint,
+) -> Option<aiColor4D> {
+    let key = CString::new(pKey).ok()?;
+    let mut color = aiColor4D::default();
+    let ret =
+        unsafe { raw_assimp::aiGetMaterialColor(pMat, key.as_ptr(), type_, index, &mut color) };
+    success(ret).map(|_| color)
+}
+
+// ---------------------------------------------------------------------------
+/** @brief Retrieve an array of float values with a specific key
+ *  from the material
+ *
+ * Pass one of the AI_MATKEY_XXX constants for the last three parameters (the
+ * example reads the #AI_MATKEY_UVTRANSFORM property of the first diffuse texture)
+ * @code
+ * let mut trafo = [0.0f32; 5];
+ * let n = aiGetMaterialFloatArray(mat, AI_MATKEY_UVTRANSFORM_BASE,
+ *    aiTextureType::Diffuse.into(), 0, &mut trafo);
+ * @endcode
+ *
+ * @param pMat Pointer to the input material. May not be NULL
+ * @param pKey Key to search for. One of the AI_MATKEY_XXX constants.
+ * @param pOut Array to receive the data, its length is the maximum
+ *   number of values read.
+ * @param type (see the code sample above)
+ * @param index (see the code sample above)
+ * @return Number of values written to pOut or None if the key has not
+ *   been found
+ */
+pub fn aiGetMaterialFloatArray(
+    pMat: &aiMaterial,
+    pKey: &str,
+    type_: c_uint,
+    index: c_uint,
+    pOut: &mut [f32],
+) -> Option<usize> {
+    let key = CString::new(pKey).ok()?;
+    let mut max = pOut.len() as c_uint;
+    let ret = unsafe {
+        raw_assimp::aiGetMaterialFloatArray(
+            pMat,
+            key.as_ptr(),
+            type_,
+            index,
+            pOut.as_mut_ptr(),
+            &mut max,
+        )
+    };
+    success(ret).map(|_| max as usize)
+}
+
+// ---------------------------------------------------------------------------
+/** @brief Retrieve an array of integer values with a specific key
+ *  from a material
+ *
+ * See the sample for aiGetMaterialFloatArray for more information.
+ */
+pub fn aiGetMaterialIntegerArray(
+    pMat: &aiMaterial,
+    pKey: &str,
+    type_: c_uint,
+    index: c_uint,
+    pOut: &mut [c_int],
+) -> Option<usize> {
+    let key = CString::new(pKey).ok()?;
+    let mut max = pOut.len() as c_uint;
+    let ret = unsafe {
+        raw_assimp::aiGetMaterialIntegerArray(
+            pMat,
+            key.as_ptr(),
+            type_,
+            index,
+            pOut.as_mut_ptr(),
+            &mut max,
+        )
+    };
+    success(ret).map(|_| max as usize)
+}
+
+// ---------------------------------------------------------------------------
+/** @brief Retrieve a string from the material property table
+ *
+ * See the sample for aiGetMaterialFloatArray for more information.
+ */
+pub fn aiGetMaterialString(
+    pMat: &aiMaterial,
+    pKey: &str,
+    type_: c_uint,
+    index: c_uint,
+) -> Option<aiString> {
+    let key = CString::new(pKey).ok()?;
+    let mut string = aiString::default();
+    let ret =
+        unsafe { raw_assimp::aiGetMaterialString(pMat, key.as_ptr(), type_, index, &mut string) };
+    success(ret).map(|_| string)
+}
+
+// ---------------------------------------------------------------------------
+/** Get the number of textures for a particular texture type.
+ *  @param pMat Pointer to the input material. May not be NULL
+ *  @param type Texture type to check for
+ *  @return Number of textures for this type.
+ *  @note A texture can be easily queried using #aiGetMaterialTexture() */
+pub fn aiGetMaterialTextureCount(pMat: &aiMaterial, type_: aiTextureType) -> c_uint {
+    unsafe { raw_assimp::aiGetMaterialTextureCount(pMat, type_.into()) }
+}
+
+// ---------------------------------------------------------------------------
+/** @brief Helper function to get all values pertaining to a particular
+ *  texture slot from a material structure.
+ *
+ *  This function is provided just for convenience. You could also read the
+ *  texture by parsing all of its properties manually. This function bundles
+ *  all of them in a huge function monster.
+ *
+ *  @param mat Pointer to the input material. May not be NULL
+ *  @param type Specifies the texture stack to read from (e.g. diffuse,
+ *     specular, height map ...).
+ *  @param index Index of the texture. The function fails if the
+ *     requested index is not available for this texture type.
+ *     #aiGetMaterialTextureCount() can be used to determine the number of
+ *     textures in a particular texture stack.
+ *  @return The texture or None if it does not exist
+ */
+pub fn aiGetMaterialTexture(
+    mat: &aiMaterial,
+    type_: aiTextureType,
+    index: c_uint,
+) -> Option<MaterialTexture> {
+    let mut path = aiString::default();
+    let mut mapping = aiTextureMapping::UV as c_uint;
+    let mut uv_index = 0;
+    let mut blend = 1.0;
+    let mut op = c_uint::MAX;
+    let mut map_mode = [aiTextureMapMode::Wrap as c_uint; 2];
+    let mut flags = 0;
+    let ret = unsafe {
+        raw_assimp::aiGetMaterialTexture(
+            mat,
+            type_.into(),
+            index,
+            &mut path,
+            &mut mapping,
+            &mut uv_index,
+            &mut blend,
+            &mut op,
+            map_mode.as_mut_ptr(),
+            &mut flags,
+        )
+    };
+    success(ret)?;
+
+    let wrap = |raw| aiTextureMapMode::from_raw(raw).unwrap_or(aiTextureMapMode::Wrap);
+    Some(MaterialTexture {
+        texture_type: type_,
+        index,
+        path: path.to_string_lossy().into_owned(),
+        mapping: aiTextureMapping::from_raw(mapping).unwrap_or(aiTextureMapping::Other),
+        uv_index,
+        blend,
+        op: aiTextureOp::from_raw(op),
+        map_mode: [wrap(map_mode[0]), wrap(map_mode[1])],
+        flags,
+    })
+}
+
+/// Where the image of a texture slot is found, see `MaterialTexture::resolve`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TexturePath {
+    /// Index into the scene's embedded textures.
+    Embedded(usize),
+    /// Path of an image file.
+    File(PathBuf),
+}
+
+impl MaterialTexture {
+    /// Whether the slot references an embedded texture (`*N`).
+    pub fn embedded_index(&self) -> Option<usize> {
+        self.path.strip_prefix('*')?.parse().ok()
+    }
+
+    /// Resolves the texture path relative to the directory of the model file.
+    ///
+    /// `*N` references resolve to embedded textures. Relative paths are
+    /// anchored at the model's directory, with `\` accepted as separator.
+    /// If the resulting file does not exist but a file with the same name
+    /// does next to the model, that one is returned, which handles absolute
+    /// paths written on the artist's machine.
+    pub fn resolve<P: AsRef<Path>>(&self, model_file: P) -> TexturePath {
+        if let Some(index) = self.embedded_index() {
+            return TexturePath::Embedded(index);
+        }
+
+        let dir = model_file
+            .as_ref()
+            .parent()
+            .unwrap_or_else(|| Path::new(""));
+        let path = self.path.trim_start_matches("file://");
+        let path = if cfg!(windows) {
+            PathBuf::from(path)
+        } else {
+            PathBuf::from(path.replace('\\', "/"))
+        };
+        let full = dir.join(&path);
+        if full.exists() {
+            return TexturePath::File(full);
+        }
+
+        match path.file_name() {
+            Some(name) if dir.join(name).exists() => TexturePath::File(dir.join(name)),
+            _ => TexturePath::File(full),
+        }
+    }
+}
+
+fn success(ret: c_int) -> Option<()> {
+    match aiReturn::from_raw(ret) {
+        aiReturn::Success => Some(()),
+        _ => None,
+    }
+}
+
+/// A texture slot of a material, see `aiGetMaterialTexture`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialTexture {
+    /// The texture stack the slot belongs to.
+    pub texture_type: aiTextureType,
+    /// Index of the slot in its texture stack.
+    pub index: c_uint,
+    /// Path of the texture as stored in the file, `*N` for embedded textures.
+    pub path: String,
+    /// How the texture coordinates are generated.
+    pub mapping: aiTextureMapping,
+    /// The UV channel used when `mapping` is `aiTextureMapping::UV`.
+    pub uv_index: c_uint,
+    /// Blend factor of the texture.
+    pub blend: f32,
+    /// How the texture is combined with the previous layers, `None` if unspecified.
+    pub op: Option<aiTextureOp>,
+    /// Wrapping mode along the U and V axes.
+    pub map_mode: [aiTextureMapMode; 2],
+    /// Combination of the aiTextureFlags_XXX values.
+    pub flags: c_uint,
+}
+
+/// A borrowed, safe view of an `aiMaterial`.
+///
+/// Getters return `None` if the material does not define the property.
+#[derive(Copy, Clone)]
+pub struct Material<'a> {
+    mat: &'a aiMaterial,
+}
+
+impl<'a> Material<'a> {
+    pub fn new(mat: &'a aiMaterial) -> Material<'a> {
+        Material { mat }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiMaterial {
+        self.mat
+    }
+
+    /// All properties of the material.
+    pub fn properties(&self) -> &'a [&'a aiMaterialProperty] {
+        unsafe { raw_ptr_slice(self.mat.mProperties, self.mat.mNumProperties) }
+    }
+
+    /// Finds a property by key, texture type and index.
+    pub fn property(
+        &self,
+        key: &str,
+        type_: c_uint,
+        index: c_uint,
+    ) -> Option<&'a aiMaterialProperty> {
+        let key = CString::new(key).ok()?;
+        let mut prop = ptr::null();
+        let ret = unsafe {
+            raw_assimp::aiGetMaterialProperty(self.mat, key.as_ptr(), type_, index, &mut prop)
+        };
+        success(ret)?;
+        unsafe { prop.as_ref() }
+    }
+
+    /// Reads a color property, see `aiGetMaterialColor`.
+    pub fn get_color(&self, key: &str) -> Option<aiColor4D> {
+        aiGetMaterialColor(self.mat, key, 0, 0)
+    }
+
+    /// Reads a scalar float property.
+    pub fn get_float(&self, key: &str) -> Option<f32> {
+        let mut value = [0.0];
+        aiGetMaterialFloatArray(self.mat, key, 0, 0, &mut value).map(|_| value[0])
+    }
+
+    /// Reads a scalar integer property.
+    pub fn get_int(&self, key: &str) -> Option<c_int> {
+        let mut value = [0];
+        aiGetMaterialIntegerArray(self.mat, key, 0, 0, &mut value).map(|_| value[0])
+    }
+
+    /// Reads a string property.
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        aiGetMaterialString(self.mat, key, 0, 0).map(|s| s.to_string_lossy().into_owned())
+    }
+
+    /// The name of the material.
+    pub fn name(&self) -> Option<String> {
+        self.get_string(AI_MATKEY_NAME)
+    }
+
+    pub fn diffuse_color(&self) -> Option<aiColor4D> {
+        self.get_color(AI_MATKEY_COLOR_DIFFUSE)
+    }
+
+    pub fn specular_color(&self) -> Option<aiColor4D> {
+        self.get_color(AI_MATKEY_COLOR_SPECULAR)
+    }
+
+    pub fn ambient_color(&self) -> Option<aiColor4D> {
+        self.get_color(AI_MATKEY_COLOR_AMBIENT)
+    }
+
+    pub fn emissive_color(&self) -> Option<aiColor4D> {
+        self.get_color(AI_MATKEY_COLOR_EMISSIVE)
+    }
+
+    /// The exponent of the phong specular equation.
+    pub fn shininess(&self) -> Option<f32> {
+        self.get_float(AI_MATKEY_SHININESS)
+    }
+
+    /// Scales the specular color of the material.
+    pub fn shininess_strength(&self) -> Option<f32> {
+        self.get_float(AI_MATKEY_SHININESS_STRENGTH)
+    }
+
+    /// Opacity of the material in the range 0 (transparent) to 1 (opaque).
+    pub fn opacity(&self) -> Option<f32> {
+        self.get_float(AI_MATKEY_OPACITY)
+    }
+
+    /// Whether backface culling must be disabled for the material, false if unspecified.
+    pub fn two_sided(&self) -> bool {
+        self.get_int(AI_MATKEY_TWOSIDED).is_some_and(|v| v != 0)
+    }
+
+    pub fn shading_model(&self) -> Option<aiShadingMode> {
+        self.get_int(AI_MATKEY_SHADING_MODEL)
+            .and_then(|v| aiShadingMode::from_raw(v as c_uint))
+    }
+
+    /// Converts the material to a format independent PBR description.
+    pub fn to_pbr(&self) -> PbrMaterial {
+        PbrMaterial::from_material(self)
+    }
+
+    /// The `index`th texture of the given type, see `aiGetMaterialTexture`.
+    pub fn texture(&self, type_: aiTextureType, index: c_uint) -> Option<MaterialTexture> {
+        aiGetMaterialTexture(self.mat, type_, index)
+    }
+
+    /// Number of textures of the given type.
+    pub fn texture_count(&self, type_: aiTextureType) -> c_uint {
+        aiGetMaterialTextureCount(self.mat, type_)
+    }
+
+    /// Every texture slot of the material, grouped by texture type.
+    pub fn textures(&self) -> Vec<MaterialTexture> {
+        aiTextureType::ALL
+            .iter()
+            .filter(|&&type_| type_ != aiTextureType::None)
+            .flat_map(|&type_| {
+                (0..self.texture_count(type_)).filter_map(move |i| self.texture(type_, i))
+            })
+            .collect()
+    }
+}
+
+impl<'a> From<&'a aiMaterial> for Material<'a> {
+    fn from(mat: &'a aiMaterial) -> Material<'a> {
+        Material::new(mat)
+    }
+}
@@ -0,0 +1,204 @@
+use std::borrow::Cow;
+use std::os::raw::c_uint;
+use std::slice;
+
+use bone::Bone;
+use data::*;
+use morph::MorphTarget;
+use util::{raw_ptr_slice, raw_slice};
+
+/// A borrowed, safe view of an `aiMesh`.
+///
+/// Per-vertex arrays are returned as slices of `num_vertices()` elements,
+/// arrays missing from the mesh are returned as empty slices or `None`.
+#[derive(Copy, Clone)]
+pub struct MeshView<'a> {
+    mesh: &'a aiMesh,
+}
+
+impl<'a> MeshView<'a> {
+    pub fn new(mesh: &'a aiMesh) -> MeshView<'a> {
+        MeshView { mesh }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiMesh {
+        self.mesh
+    }
+
+    /// The name of the mesh, might be empty.
+    pub fn name(&self) -> Cow<'a, str> {
+        self.mesh.mName.to_string_lossy()
+    }
+
+    /// Bitwise combination of the members of the aiPrimitiveType enum.
+    pub fn primitive_types(&self) -> c_uint {
+        self.mesh.mPrimitiveTypes
+    }
+
+    /// Index of the mesh's material in the scene's material list.
+    pub fn material_index(&self) -> usize {
+        self.mesh.mMaterialIndex as usize
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.mesh.mNumVertices as usize
+    }
+
+    pub fn num_faces(&self) -> usize {
+        self.mesh.mNumFaces as usize
+    }
+
+    /// Vertex positions.
+    pub fn positions(&self) -> &'a [aiVector3D] {
+        self.vertex_slice(self.mesh.mVertices)
+    }
+
+    /// Vertex normals, empty if the mesh has none.
+    pub fn normals(&self) -> &'a [aiVector3D] {
+        self.vertex_slice(self.mesh.mNormals)
+    }
+
+    /// Vertex tangents, empty if the mesh has none.
+    pub fn tangents(&self) -> &'a [aiVector3D] {
+        self.vertex_slice(self.mesh.mTangents)
+    }
+
+    /// Vertex bitangents, empty if the mesh has none.
+    pub fn bitangents(&self) -> &'a [aiVector3D] {
+        self.vertex_slice(self.mesh.mBitangents)
+    }
+
+    /// Number of components (1 to 3) of the given UV channel, 0 if the channel is missing.
+    pub fn uv_components(&self, channel: usize) -> usize {
+        if channel >= AI_MAX_NUMBER_OF_TEXTURECOORDS || self.mesh.mTextureCoords[channel].is_null()
+        {
+            0
+        } else {
+            self.mesh.mNumUVComponents[channel] as usize
+        }
+    }
+
+    /// Texture coordinates of the given UV channel.
+    ///
+    /// Components beyond `uv_components(channel)` are set to zero.
+    pub fn uv_channel(&self, channel: usize) -> Option<&'a [aiVector3D]> {
+        if self.uv_components(channel) == 0 {
+            None
+        } else {
+            Some(self.vertex_slice(self.mesh.mTextureCoords[channel]))
+        }
+    }
+
+    /// The name of the given UV channel, if the file names it.
+    #[cfg(assimp_5_2)]
+    pub fn uv_channel_name(&self, channel: usize) -> Option<Cow<'a, str>> {
+        if channel >= AI_MAX_NUMBER_OF_TEXTURECOORDS || self.mesh.mTextureCoordsNames.is_null() {
+            return None;
+        }
+        let name = unsafe { *self.mesh.mTextureCoordsNames.add(channel) };
+        unsafe { name.as_ref() }.map(|name| name.to_string_lossy())
+    }
+
+    /// The name of the given UV channel, if the file names it.
+    #[cfg(not(assimp_5_2))]
+    pub fn uv_channel_name(&self, _channel: usize) -> Option<Cow<'a, str>> {
+        None
+    }
+
+    /// Number of UV channels present in the mesh.
+    pub fn num_uv_channels(&self) -> usize {
+        (0..AI_MAX_NUMBER_OF_TEXTURECOORDS)
+            .take_while(|&c| self.uv_components(c) != 0)
+            .count()
+    }
+
+    /// Vertex colors of the given color set.
+    pub fn color_channel(&self, channel: usize) -> Option<&'a [aiColor4D]> {
+        if channel >= AI_MAX_NUMBER_OF_COLOR_SETS || self.mesh.mColors[channel].is_null() {
+            None
+        } else {
+            Some(self.vertex_slice(self.mesh.mColors[channel]))
+        }
+    }
+
+    /// Number of vertex color sets present in the mesh.
+    pub fn num_color_channels(&self) -> usize {
+        (0..AI_MAX_NUMBER_OF_COLOR_SETS)
+            .take_while(|&c| !self.mesh.mColors[c].is_null())
+            .count()
+    }
+
+    /// The bounding box of the mesh, only set when imported with `GenBoundingBoxes`.
+    pub fn aabb(&self) -> aiAABB {
+        self.mesh.mAABB
+    }
+
+    /// The bones deforming the mesh.
+    pub fn bones(&self) -> impl Iterator<Item = Bone<'a>> + 'a {
+        unsafe { raw_ptr_slice(self.mesh.mBones, self.mesh.mNumBones) }
+            .iter()
+            .map(|&bone| Bone::new(bone))
+    }
+
+    pub fn num_bones(&self) -> usize {
+        self.mesh.mNumBones as usize
+    }
+
+    /// Attachment meshes with replacement vertex data, used as morph targets.
+    pub fn anim_meshes(&self) -> &'a [&'a aiAnimMesh] {
+        unsafe { raw_ptr_slice(self.mesh.mAnimMeshes, self.mesh.mNumAnimMeshes) }
+    }
+
+    /// How the anim meshes are combined, `Unknown` for unknown values.
+    pub fn morphing_method(&self) -> aiMorphingMethod {
+        aiMorphingMethod::from_raw(self.mesh.mMethod).unwrap_or(aiMorphingMethod::Unknown)
+    }
+
+    /// The anim meshes as deltas relative to this mesh.
+    pub fn morph_targets(&self) -> Vec<MorphTarget> {
+        self.anim_meshes()
+            .iter()
+            .map(|&anim_mesh| MorphTarget::new(*self, anim_mesh))
+            .collect()
+    }
+
+    /// Iterates the faces as slices of vertex indices.
+    pub fn faces(&self) -> Faces<'a> {
+        Faces {
+            faces: unsafe { raw_slice(self.mesh.mFaces, self.mesh.mNumFaces) }.iter(),
+        }
+    }
+
+    fn vertex_slice<T>(&self, ptr: *const T) -> &'a [T] {
+        unsafe { raw_slice(ptr, self.mesh.mNumVertices) }
+    }
+}
+
+impl<'a> From<&'a aiMesh> for MeshView<'a> {
+    fn from(mesh: &'a aiMesh) -> MeshView<'a> {
+        MeshView::new(mesh)
+    }
+}
+
+/// Iterator over the faces of a mesh, see `MeshView::faces`.
+#[derive(Clone)]
+pub struct Faces<'a> {
+    faces: slice::Iter<'a, aiFace>,
+}
+
+impl<'a> Iterator for Faces<'a> {
+    type Item = &'a [c_uint];
+
+    fn next(&mut self) -> Option<&'a [c_uint]> {
+        self.faces
+            .next()
+            .map(|face| unsafe { raw_slice(face.mIndices, face.mNumIndices) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.faces.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Faces<'a> {}
@@ -0,0 +1,308 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+
+use data::*;
+use util::raw_slice;
+
+/// A typed value of a metadata entry.
+#[derive(Clone, Debug)]
+pub enum MetadataValue<'a> {
+    Bool(bool),
+    Int32(i32),
+    UInt64(u64),
+    Float(f32),
+    Double(f64),
+    String(Cow<'a, str>),
+    Vector3D(aiVector3D),
+    Metadata(Metadata<'a>),
+    Int64(i64),
+    UInt32(u32),
+}
+
+impl<'a> MetadataValue<'a> {
+    /// Reads the value of `entry`, `None` for unknown types and missing values.
+    pub fn from_entry(entry: &'a aiMetadataEntry) -> Option<MetadataValue<'a>> {
+        let data = entry.mData;
+        if data.is_null() {
+            return None;
+        }
+
+        let value = unsafe {
+            match aiMetadataType::from_raw(entry.mType)? {
+                aiMetadataType::Bool => MetadataValue::Bool(*(data as *const u8) != 0),
+                aiMetadataType::Int32 => MetadataValue::Int32(*(data as *const i32)),
+                aiMetadataType::UInt64 => MetadataValue::UInt64(*(data as *const u64)),
+                aiMetadataType::Float => MetadataValue::Float(*(data as *const f32)),
+                aiMetadataType::Double => MetadataValue::Double(*(data as *const f64)),
+                aiMetadataType::AiString => {
+                    MetadataValue::String((*(data as *const aiString)).to_string_lossy())
+                }
+                aiMetadataType::AiVector3D => MetadataValue::Vector3D(*(data as *const aiVector3D)),
+                aiMetadataType::AiMetadata => {
+                    MetadataValue::Metadata(Metadata::new(&*(data as *const aiMetadata)))
+                }
+                aiMetadataType::Int64 => MetadataValue::Int64(*(data as *const i64)),
+                aiMetadataType::UInt32 => MetadataValue::UInt32(*(data as *const u32)),
+            }
+        };
+        Some(value)
+    }
+
+    /// The value as a floating point number, `None` for non-numeric values.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            MetadataValue::Bool(v) => Some(if v { 1.0 } else { 0.0 }),
+            MetadataValue::Int32(v) => Some(v as f64),
+            MetadataValue::UInt64(v) => Some(v as f64),
+            MetadataValue::Float(v) => Some(v as f64),
+            MetadataValue::Double(v) => Some(v),
+            MetadataValue::Int64(v) => Some(v as f64),
+            MetadataValue::UInt32(v) => Some(v as f64),
+            _ => None,
+        }
+    }
+
+    /// The value as an integer, `None` for non-integer values.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            MetadataValue::Bool(v) => Some(v as i64),
+            MetadataValue::Int32(v) => Some(v as i64),
+            MetadataValue::UInt64(v) => Some(v as i64),
+            MetadataValue::Int64(v) => Some(v),
+            MetadataValue::UInt32(v) => Some(v as i64),
+            _ => None,
+        }
+    }
+
+    /// The value of string entries.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            MetadataValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// A borrowed, safe view of an `aiMetadata` key-value store.
+#[derive(Copy, Clone)]
+pub struct Metadata<'a> {
+    meta: &'a aiMetadata,
+}
+
+impl<'a> Metadata<'a> {
+    pub fn new(meta: &'a aiMetadata) -> Metadata<'a> {
+        Metadata { meta }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiMetadata {
+        self.meta
+    }
+
+    /// The number of entries returned by `iter`.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Iterates the entries, entries without a value or with an unknown type are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = (Cow<'a, str>, MetadataValue<'a>)> + 'a {
+        self.keys()
+            .iter()
+            .zip(self.values().iter())
+            .filter_map(|(key, entry)| {
+                MetadataValue::from_entry(entry).map(|value| (key.to_string_lossy(), value))
+            })
+    }
+
+    /// The value of the entry with the given key.
+    pub fn get(&self, key: &str) -> Option<MetadataValue<'a>> {
+        self.keys()
+            .iter()
+            .zip(self.values().iter())
+            .find(|(k, _)| k.as_bytes() == key.as_bytes())
+            .and_then(|(_, entry)| MetadataValue::from_entry(entry))
+    }
+
+    /// The scale of the file's units, set by the FBX importer in centimetres per unit.
+    pub fn unit_scale_factor(&self) -> Option<f64> {
+        self.get("UnitScaleFactor")?.as_f64()
+    }
+
+    /// The up direction of the file, set by the FBX importer as the `UpAxis`
+    /// index and `UpAxisSign` entries.
+    pub fn up_axis(&self) -> Option<aiVector3D> {
+        let axis = self.get("UpAxis")?.as_i64()?;
+        let sign = match self.get("UpAxisSign").and_then(|v| v.as_i64()) {
+            Some(s) if s < 0 => -1.0,
+            _ => 1.0,
+        };
+        match axis {
+            0 => Some(aiVector3D::new(sign, 0.0, 0.0)),
+            1 => Some(aiVector3D::new(0.0, sign, 0.0)),
+            2 => Some(aiVector3D::new(0.0, 0.0, sign)),
+            _ => None,
+        }
+    }
+
+    /// The name of the importer which loaded the file.
+    pub fn source_format(&self) -> Option<Cow<'a, str>> {
+        match self.get(AI_METADATA_SOURCE_FORMAT)? {
+            MetadataValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn keys(&self) -> &'a [aiString] {
+        unsafe { raw_slice(self.meta.mKeys, self.meta.mNumProperties) }
+    }
+
+    fn values(&self) -> &'a [aiMetadataEntry] {
+        unsafe { raw_slice(self.meta.mValues, self.meta.mNumProperties) }
+    }
+}
+
+impl<'a> Debug for Metadata<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> From<&'a aiMetadata> for Metadata<'a> {
+    fn from(meta: &'a aiMetadata) -> Metadata<'a> {
+        Metadata::new(meta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::c_void;
+    use std::os::raw::c_uint;
+    use std::ptr;
+
+    fn entry<T>(type_: aiMetadataType, value: &T) -> aiMetadataEntry {
+        aiMetadataEntry {
+            mType: type_.into(),
+            mData: value as *const T as *const c_void,
+        }
+    }
+
+    fn metadata(keys: &[aiString], values: &[aiMetadataEntry]) -> aiMetadata {
+        aiMetadata {
+            mNumProperties: keys.len() as c_uint,
+            mKeys: keys.as_ptr(),
+            mValues: values.as_ptr(),
+        }
+    }
+
+    #[test]
+    fn decodes_every_entry_type() {
+        let (b, i32_, u64_, f, d) = (1u8, -7i32, 1u64 << 40, 0.5f32, 2.54f64);
+        let (i64_, u32_) = (-(1i64 << 40), 7u32);
+        let s = aiString::from("FBX");
+        let v = aiVector3D::new(1.0, 2.0, 3.0);
+        let nested_keys = [aiString::from("inner")];
+        let nested_values = [entry(aiMetadataType::Int32, &i32_)];
+        let nested = metadata(&nested_keys, &nested_values);
+
+        fn value(e: &aiMetadataEntry) -> MetadataValue<'_> {
+            MetadataValue::from_entry(e).unwrap()
+        }
+        assert!(matches!(
+            value(&entry(aiMetadataType::Bool, &b)),
+            MetadataValue::Bool(true)
+        ));
+        assert!(matches!(
+            value(&entry(aiMetadataType::Int32, &i32_)),
+            MetadataValue::Int32(-7)
+        ));
+        assert!(matches!(
+            value(&entry(aiMetadataType::UInt64, &u64_)),
+            MetadataValue::UInt64(x) if x == 1 << 40
+        ));
+        assert!(
+            matches!(value(&entry(aiMetadataType::Float, &f)), MetadataValue::Float(x) if x == 0.5)
+        );
+        assert!(
+            matches!(value(&entry(aiMetadataType::Double, &d)), MetadataValue::Double(x) if x == 2.54)
+        );
+        assert_eq!(
+            value(&entry(aiMetadataType::AiString, &s)).as_str(),
+            Some("FBX")
+        );
+        assert!(matches!(
+            value(&entry(aiMetadataType::AiVector3D, &v)),
+            MetadataValue::Vector3D(x) if (x.x, x.y, x.z) == (1.0, 2.0, 3.0)
+        ));
+        assert!(matches!(
+            value(&entry(aiMetadataType::AiMetadata, &nested)),
+            MetadataValue::Metadata(m) if m.get("inner").and_then(|v| v.as_i64()) == Some(-7)
+        ));
+        assert!(matches!(
+            value(&entry(aiMetadataType::Int64, &i64_)),
+            MetadataValue::Int64(x) if x == -(1 << 40)
+        ));
+        assert!(matches!(
+            value(&entry(aiMetadataType::UInt32, &u32_)),
+            MetadataValue::UInt32(7)
+        ));
+    }
+
+    #[test]
+    fn skips_missing_and_unknown_entries() {
+        let value = 1i32;
+        let missing = aiMetadataEntry {
+            mType: aiMetadataType::Int32.into(),
+            mData: ptr::null(),
+        };
+        let unknown = aiMetadataEntry {
+            mType: 42,
+            mData: &value as *const i32 as *const c_void,
+        };
+        assert!(MetadataValue::from_entry(&missing).is_none());
+        assert!(MetadataValue::from_entry(&unknown).is_none());
+
+        let keys = [
+            aiString::from("a"),
+            aiString::from("b"),
+            aiString::from("c"),
+        ];
+        let values = [missing, entry(aiMetadataType::Int32, &value), unknown];
+        let raw = metadata(&keys, &values);
+        let meta = Metadata::new(&raw);
+        assert_eq!(meta.len(), 1);
+        assert_eq!(meta.iter().count(), meta.len());
+        assert!(!meta.is_empty());
+        assert!(meta.get("a").is_none());
+    }
+
+    #[test]
+    fn reads_the_fbx_unit_and_axis_entries() {
+        let (axis, sign, scale) = (1i32, -1i32, 2.54f64);
+        let keys = [
+            aiString::from("UpAxis"),
+            aiString::from("UpAxisSign"),
+            aiString::from("UnitScaleFactor"),
+            aiString::from(AI_METADATA_SOURCE_FORMAT),
+        ];
+        let format = aiString::from("FBX");
+        let values = [
+            entry(aiMetadataType::Int32, &axis),
+            entry(aiMetadataType::Int32, &sign),
+            entry(aiMetadataType::Double, &scale),
+            entry(aiMetadataType::AiString, &format),
+        ];
+        let raw = metadata(&keys, &values);
+        let meta = Metadata::new(&raw);
+
+        let up = meta.up_axis().unwrap();
+        assert_eq!((up.x, up.y, up.z), (0.0, -1.0, 0.0));
+        assert_eq!(meta.unit_scale_factor(), Some(2.54));
+        assert_eq!(meta.source_format().as_deref(), Some("FBX"));
+        assert_eq!(meta.len(), 4);
+    }
+}
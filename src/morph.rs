@@ -0,0 +1,258 @@
+use std::borrow::Cow;
+
+use data::*;
+use mesh::MeshView;
+use util::{normalize, raw_slice};
+
+/// A morph target of a mesh as offsets from the base mesh.
+///
+/// Delta arrays are empty when the target does not replace the attribute.
+#[derive(Clone)]
+pub struct MorphTarget {
+    pub name: String,
+    /// The default weight of the target.
+    pub weight: f32,
+    pub position_deltas: Vec<aiVector3D>,
+    pub normal_deltas: Vec<aiVector3D>,
+    pub tangent_deltas: Vec<aiVector3D>,
+}
+
+impl MorphTarget {
+    /// Computes the deltas of `anim_mesh` relative to its host `mesh`.
+    pub fn new(mesh: MeshView, anim_mesh: &aiAnimMesh) -> MorphTarget {
+        let len = anim_mesh.mNumVertices;
+        let deltas = |base: &[aiVector3D], target: *const aiVector3D| {
+            let target = unsafe { raw_slice(target, len) };
+            base.iter()
+                .zip(target.iter())
+                .map(|(b, t)| aiVector3D::new(t.x - b.x, t.y - b.y, t.z - b.z))
+                .collect()
+        };
+
+        MorphTarget {
+            name: anim_mesh.mName.to_string_lossy().into_owned(),
+            weight: anim_mesh.mWeight,
+            position_deltas: deltas(mesh.positions(), anim_mesh.mVertices),
+            normal_deltas: deltas(mesh.normals(), anim_mesh.mNormals),
+            tangent_deltas: deltas(mesh.tangents(), anim_mesh.mTangents),
+        }
+    }
+}
+
+/// Vertex attributes of a mesh with its morph targets applied, see `blend_morph_targets`.
+#[derive(Clone)]
+pub struct MorphedVertices {
+    pub positions: Vec<aiVector3D>,
+    /// Empty if the mesh has no normals.
+    pub normals: Vec<aiVector3D>,
+    /// Empty if the mesh has no tangents.
+    pub tangents: Vec<aiVector3D>,
+}
+
+/// Blends the base attributes of `mesh` with its morph `targets` by `weights`,
+/// following the mesh's `aiMorphingMethod`.
+///
+/// - `Vertex_Blend` interpolates between the base and the targets, the base
+///   is weighted by `1 - sum(weights)`.
+/// - `Morph_Relative` and `Unknown` add the target deltas scaled by their
+///   weight to the base.
+/// - `Morph_Normalized` scales the weights to sum up to one before blending,
+///   so the base only remains while all weights are zero.
+///
+/// Missing weights count as zero, normals and tangents are renormalized.
+pub fn blend_morph_targets(
+    mesh: MeshView,
+    targets: &[MorphTarget],
+    weights: &[f32],
+) -> MorphedVertices {
+    let mut positions = mesh.positions().to_vec();
+    let mut normals = mesh.normals().to_vec();
+    let mut tangents = mesh.tangents().to_vec();
+
+    let weights = &weights[..weights.len().min(targets.len())];
+    let weights = delta_weights(mesh.morphing_method(), weights);
+    for (target, &weight) in targets.iter().zip(weights.iter()) {
+        if weight == 0.0 {
+            continue;
+        }
+        add_deltas(&mut positions, &target.position_deltas, weight);
+        add_deltas(&mut normals, &target.normal_deltas, weight);
+        add_deltas(&mut tangents, &target.tangent_deltas, weight);
+    }
+
+    for v in normals.iter_mut().chain(tangents.iter_mut()) {
+        *v = normalize(*v);
+    }
+
+    MorphedVertices {
+        positions,
+        normals,
+        tangents,
+    }
+}
+
+/// The weights of the target deltas for a morphing method.
+fn delta_weights(method: aiMorphingMethod, weights: &[f32]) -> Vec<f32> {
+    match method {
+        // the deltas are target - base, so interpolating with the base weighted by
+        // 1 - sum(w) is the same as adding the weighted deltas:
+        // base * (1 - sum(w)) + sum(w * target) == base + sum(w * (target - base))
+        aiMorphingMethod::Vertex_Blend
+        | aiMorphingMethod::Morph_Relative
+        | aiMorphingMethod::Unknown => weights.to_vec(),
+        aiMorphingMethod::Morph_Normalized => {
+            let sum: f32 = weights.iter().sum();
+            if sum != 0.0 {
+                weights.iter().map(|w| w / sum).collect()
+            } else {
+                weights.to_vec()
+            }
+        }
+    }
+}
+
+fn add_deltas(values: &mut [aiVector3D], deltas: &[aiVector3D], weight: f32) {
+    for (v, d) in values.iter_mut().zip(deltas.iter()) {
+        *v = aiVector3D::new(v.x + d.x * weight, v.y + d.y * weight, v.z + d.z * weight);
+    }
+}
+
+/// A borrowed, safe view of an `aiMeshMorphAnim`, the morph target weights
+/// of a mesh over time.
+#[derive(Copy, Clone)]
+pub struct MorphAnim<'a> {
+    anim: &'a aiMeshMorphAnim,
+}
+
+impl<'a> MorphAnim<'a> {
+    pub fn new(anim: &'a aiMeshMorphAnim) -> MorphAnim<'a> {
+        MorphAnim { anim }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiMeshMorphAnim {
+        self.anim
+    }
+
+    /// The name of the animated mesh.
+    pub fn mesh_name(&self) -> Cow<'a, str> {
+        self.anim.mName.to_string_lossy()
+    }
+
+    pub fn keys(&self) -> &'a [aiMeshMorphKey] {
+        unsafe { raw_slice(self.anim.mKeys, self.anim.mNumKeys) }
+    }
+
+    /// Linearly interpolates the weights of `num_targets` morph targets at `ticks`.
+    ///
+    /// Times outside of the keys use the first or last key, targets not
+    /// mentioned by a key have zero weight.
+    pub fn sample_weights(&self, ticks: f64, num_targets: usize) -> Vec<f32> {
+        let keys = self.keys();
+        let mut weights = vec![0.0; num_targets];
+        if keys.is_empty() {
+            return weights;
+        }
+
+        let next = keys.partition_point(|key| key.mTime <= ticks);
+        let (a, b, t) = if next == 0 {
+            (&keys[0], &keys[0], 0.0)
+        } else if next == keys.len() {
+            (&keys[next - 1], &keys[next - 1], 0.0)
+        } else {
+            let (a, b) = (&keys[next - 1], &keys[next]);
+            let span = b.mTime - a.mTime;
+            let t = if span > 0.0 {
+                (ticks - a.mTime) / span
+            } else {
+                0.0
+            };
+            (a, b, t)
+        };
+
+        add_key_weights(&mut weights, a, 1.0 - t);
+        if t > 0.0 {
+            add_key_weights(&mut weights, b, t);
+        }
+        weights
+    }
+}
+
+impl<'a> From<&'a aiMeshMorphAnim> for MorphAnim<'a> {
+    fn from(anim: &'a aiMeshMorphAnim) -> MorphAnim<'a> {
+        MorphAnim::new(anim)
+    }
+}
+
+fn add_key_weights(weights: &mut [f32], key: &aiMeshMorphKey, scale: f64) {
+    let values = unsafe { raw_slice(key.mValues, key.mNumValuesAndWeights) };
+    let key_weights = unsafe { raw_slice(key.mWeights, key.mNumValuesAndWeights) };
+    for (&target, &weight) in values.iter().zip(key_weights.iter()) {
+        if let Some(w) = weights.get_mut(target as usize) {
+            *w += (weight * scale) as f32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_util::{self, assert_close};
+
+    fn target(dx: f32, dy: f32) -> MorphTarget {
+        MorphTarget {
+            name: String::new(),
+            weight: 0.0,
+            position_deltas: vec![aiVector3D::new(dx, dy, 0.0)],
+            normal_deltas: Vec::new(),
+            tangent_deltas: Vec::new(),
+        }
+    }
+
+    fn blend(method: aiMorphingMethod, weights: &[f32]) -> aiVector3D {
+        let base = [aiVector3D::new(2.0, 0.0, 0.0)];
+        let mut mesh = test_util::mesh(&base, &[]);
+        mesh.mMethod = method.into();
+
+        let targets = [target(1.0, 0.0), target(0.0, 1.0)];
+        blend_morph_targets(MeshView::new(&mesh), &targets, weights).positions[0]
+    }
+
+    #[test]
+    fn relative_morphing_adds_weighted_deltas() {
+        assert_close(
+            blend(aiMorphingMethod::Morph_Relative, &[0.25, 0.25]),
+            2.25,
+            0.25,
+            0.0,
+        );
+        assert_close(blend(aiMorphingMethod::Unknown, &[1.0]), 3.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn vertex_blend_weights_the_base_by_the_remainder() {
+        // 0.5 * (2, 0) + 0.25 * (3, 0) + 0.25 * (2, 1)
+        assert_close(
+            blend(aiMorphingMethod::Vertex_Blend, &[0.25, 0.25]),
+            2.25,
+            0.25,
+            0.0,
+        );
+    }
+
+    #[test]
+    fn normalized_morphing_rescales_the_weights() {
+        assert_close(
+            blend(aiMorphingMethod::Morph_Normalized, &[0.25, 0.25]),
+            2.5,
+            0.5,
+            0.0,
+        );
+        assert_close(
+            blend(aiMorphingMethod::Morph_Normalized, &[0.0, 0.0]),
+            2.0,
+            0.0,
+            0.0,
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::os::raw::c_uint;
+
+use data::*;
+use metadata::Metadata;
+use util::{raw_ptr_slice, raw_slice};
+
+/// A borrowed, safe view of an `aiNode` in the scene graph.
+#[derive(Copy, Clone)]
+pub struct Node<'a> {
+    node: &'a aiNode,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(node: &'a aiNode) -> Node<'a> {
+        Node { node }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiNode {
+        self.node
+    }
+
+    /// The name of the node, might be empty.
+    pub fn name(&self) -> Cow<'a, str> {
+        self.node.mName.to_string_lossy()
+    }
+
+    /// The transformation relative to the parent node.
+    pub fn transformation(&self) -> aiMatrix4x4 {
+        self.node.mTransformation
+    }
+
+    /// The transformation relative to the root of the hierarchy.
+    pub fn world_transformation(&self) -> aiMatrix4x4 {
+        match self.parent() {
+            Some(parent) => parent.world_transformation() * self.node.mTransformation,
+            None => self.node.mTransformation,
+        }
+    }
+
+    /// The parent node, `None` for the root node.
+    pub fn parent(&self) -> Option<Node<'a>> {
+        unsafe { self.node.mParent.as_ref() }.map(Node::new)
+    }
+
+    /// The child nodes.
+    pub fn children(&self) -> impl Iterator<Item = Node<'a>> + 'a {
+        unsafe { raw_ptr_slice(self.node.mChildren, self.node.mNumChildren) }
+            .iter()
+            .map(|&child| Node::new(child))
+    }
+
+    pub fn num_children(&self) -> usize {
+        self.node.mNumChildren as usize
+    }
+
+    /// Indices of the node's meshes in the scene's mesh list.
+    pub fn mesh_indices(&self) -> &'a [c_uint] {
+        unsafe { raw_slice(self.node.mMeshes, self.node.mNumMeshes) }
+    }
+
+    /// Metadata associated with the node.
+    pub fn metadata(&self) -> Option<&'a aiMetadata> {
+        unsafe { self.node.mMetaData.as_ref() }
+    }
+
+    /// Safe view of the node's metadata.
+    pub fn metadata_view(&self) -> Option<Metadata<'a>> {
+        self.metadata().map(Metadata::new)
+    }
+
+    /// Finds the first node with the given name in this subtree, depth-first.
+    pub fn find(&self, name: &str) -> Option<Node<'a>> {
+        self.depth_first()
+            .map(|(node, _)| node)
+            .find(|node| node.node.mName.as_bytes() == name.as_bytes())
+    }
+
+    /// Iterates this subtree depth-first (pre-order), yielding each node
+    /// with its world transformation.
+    pub fn depth_first(&self) -> DepthFirst<'a> {
+        DepthFirst {
+            stack: vec![(*self, self.world_transformation())],
+        }
+    }
+
+    /// Iterates this subtree breadth-first, yielding each node with its
+    /// world transformation.
+    pub fn breadth_first(&self) -> BreadthFirst<'a> {
+        let mut queue = VecDeque::new();
+        queue.push_back((*self, self.world_transformation()));
+        BreadthFirst { queue }
+    }
+}
+
+impl<'a> From<&'a aiNode> for Node<'a> {
+    fn from(node: &'a aiNode) -> Node<'a> {
+        Node::new(node)
+    }
+}
+
+/// Depth-first iterator over a node hierarchy, see `Node::depth_first`.
+pub struct DepthFirst<'a> {
+    stack: Vec<(Node<'a>, aiMatrix4x4)>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (Node<'a>, aiMatrix4x4);
+
+    fn next(&mut self) -> Option<(Node<'a>, aiMatrix4x4)> {
+        let (node, world) = self.stack.pop()?;
+        let first = self.stack.len();
+        self.stack.extend(
+            node.children()
+                .map(|child| (child, world * child.transformation())),
+        );
+        self.stack[first..].reverse();
+        Some((node, world))
+    }
+}
+
+/// Breadth-first iterator over a node hierarchy, see `Node::breadth_first`.
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<(Node<'a>, aiMatrix4x4)>,
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = (Node<'a>, aiMatrix4x4);
+
+    fn next(&mut self) -> Option<(Node<'a>, aiMatrix4x4)> {
+        let (node, world) = self.queue.pop_front()?;
+        self.queue.extend(
+            node.children()
+                .map(|child| (child, world * child.transformation())),
+        );
+        Some((node, world))
+    }
+}
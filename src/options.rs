@@ -0,0 +1,283 @@
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::os::raw::{c_int, c_uint};
+
+use data::*;
+use error::Error;
+use raw_assimp;
+
+/// An owned `aiPropertyStore`, released when dropped.
+pub struct PropertyStore {
+    ptr: *mut aiPropertyStore,
+}
+
+impl PropertyStore {
+    pub fn new() -> Result<PropertyStore, Error> {
+        raw_assimp::check_loaded()?;
+        let ptr = unsafe { raw_assimp::aiCreatePropertyStore() };
+        if ptr.is_null() {
+            return Err(Error::AllocationFailed);
+        }
+        Ok(PropertyStore { ptr })
+    }
+
+    pub fn set_integer(&mut self, name: &str, value: i32) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        unsafe { raw_assimp::aiSetImportPropertyInteger(self.ptr, name.as_ptr(), value as c_int) };
+        Ok(())
+    }
+
+    pub fn set_float(&mut self, name: &str, value: f32) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        unsafe { raw_assimp::aiSetImportPropertyFloat(self.ptr, name.as_ptr(), value) };
+        Ok(())
+    }
+
+    /// Sets a string property, truncated to `MAXLEN - 1` bytes.
+    pub fn set_string(&mut self, name: &str, value: &str) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        let value = aiString::from(value);
+        unsafe { raw_assimp::aiSetImportPropertyString(self.ptr, name.as_ptr(), &value) };
+        Ok(())
+    }
+
+    pub fn set_matrix(&mut self, name: &str, value: &aiMatrix4x4) -> Result<(), Error> {
+        let name = CString::new(name)?;
+        unsafe { raw_assimp::aiSetImportPropertyMatrix(self.ptr, name.as_ptr(), value) };
+        Ok(())
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &aiPropertyStore {
+        unsafe { &*self.ptr }
+    }
+}
+
+impl Drop for PropertyStore {
+    fn drop(&mut self) {
+        unsafe { raw_assimp::aiReleasePropertyStore(self.ptr) }
+    }
+}
+
+/// Integer properties are stored as `i32`, larger counts and flags saturate.
+fn clamped(value: u32) -> i32 {
+    i32::try_from(value).unwrap_or(i32::MAX)
+}
+
+/// The value of an importer property.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    Integer(i32),
+    Float(f32),
+    String(String),
+    Matrix(aiMatrix4x4),
+}
+
+/// Importer and post-processing settings, see the `AI_CONFIG_*` keys.
+///
+/// The settings are only handed to assimp when importing, through a
+/// `PropertyStore` created by `to_property_store`.
+///
+/// Assimp stores integer properties as `i32`, the setters taking `u32`
+/// counts or flags clamp values above `i32::MAX`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportOptions {
+    properties: Vec<(String, PropertyValue)>,
+}
+
+impl ImportOptions {
+    pub fn new() -> ImportOptions {
+        ImportOptions::default()
+    }
+
+    /// Sets a property, replacing earlier values of the same key.
+    pub fn property(mut self, key: &str, value: PropertyValue) -> ImportOptions {
+        self.properties.retain(|(k, _)| k != key);
+        self.properties.push((key.to_string(), value));
+        self
+    }
+
+    pub fn integer(self, key: &str, value: i32) -> ImportOptions {
+        self.property(key, PropertyValue::Integer(value))
+    }
+
+    pub fn float(self, key: &str, value: f32) -> ImportOptions {
+        self.property(key, PropertyValue::Float(value))
+    }
+
+    /// Boolean properties are stored as integers.
+    pub fn bool(self, key: &str, value: bool) -> ImportOptions {
+        self.integer(key, value as i32)
+    }
+
+    pub fn string(self, key: &str, value: &str) -> ImportOptions {
+        self.property(key, PropertyValue::String(value.to_string()))
+    }
+
+    pub fn matrix(self, key: &str, value: aiMatrix4x4) -> ImportOptions {
+        self.property(key, PropertyValue::Matrix(value))
+    }
+
+    /// The properties set so far, in order.
+    pub fn properties(&self) -> &[(String, PropertyValue)] {
+        &self.properties
+    }
+
+    /// `AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY`, the scale applied by `GlobalScale`.
+    pub fn global_scale_factor(self, scale: f32) -> ImportOptions {
+        self.float(AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY, scale)
+    }
+
+    /// `AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE`, in degrees, for `CalcTangentSpace`.
+    pub fn tangent_smoothing_angle(self, degrees: f32) -> ImportOptions {
+        self.float(AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE, degrees)
+    }
+
+    /// `AI_CONFIG_PP_GSN_MAX_SMOOTHING_ANGLE`, in degrees, for `GenSmoothNormals`.
+    pub fn normal_smoothing_angle(self, degrees: f32) -> ImportOptions {
+        self.float(AI_CONFIG_PP_GSN_MAX_SMOOTHING_ANGLE, degrees)
+    }
+
+    /// `AI_CONFIG_PP_RRM_EXCLUDE_LIST`, space separated material names kept by
+    /// `RemoveRedundantMaterials`.
+    pub fn keep_materials(self, names: &str) -> ImportOptions {
+        self.string(AI_CONFIG_PP_RRM_EXCLUDE_LIST, names)
+    }
+
+    /// `AI_CONFIG_PP_PTV_KEEP_HIERARCHY` for `PreTransformVertices`.
+    pub fn pretransform_keep_hierarchy(self, keep: bool) -> ImportOptions {
+        self.bool(AI_CONFIG_PP_PTV_KEEP_HIERARCHY, keep)
+    }
+
+    /// `AI_CONFIG_PP_PTV_NORMALIZE`, scales the scene into [-1, 1] in `PreTransformVertices`.
+    pub fn pretransform_normalize(self, normalize: bool) -> ImportOptions {
+        self.bool(AI_CONFIG_PP_PTV_NORMALIZE, normalize)
+    }
+
+    /// `AI_CONFIG_PP_PTV_ROOT_TRANSFORMATION`, a transformation applied to the
+    /// root node by `PreTransformVertices`.
+    pub fn pretransform_root_transformation(self, transformation: aiMatrix4x4) -> ImportOptions {
+        self.bool(AI_CONFIG_PP_PTV_ADD_ROOT_TRANSFORMATION, true)
+            .matrix(AI_CONFIG_PP_PTV_ROOT_TRANSFORMATION, transformation)
+    }
+
+    /// `AI_CONFIG_PP_FD_REMOVE`, removes degenerate primitives in `FindDegenerates`
+    /// instead of converting them to lines and points.
+    pub fn remove_degenerates(self, remove: bool) -> ImportOptions {
+        self.bool(AI_CONFIG_PP_FD_REMOVE, remove)
+    }
+
+    /// `AI_CONFIG_PP_FD_CHECKAREA`, treats triangles with a tiny area as degenerate.
+    pub fn check_degenerate_area(self, check: bool) -> ImportOptions {
+        self.bool(AI_CONFIG_PP_FD_CHECKAREA, check)
+    }
+
+    /// `AI_CONFIG_PP_OG_EXCLUDE_LIST`, space separated node names kept by `OptimizeGraph`.
+    pub fn keep_nodes(self, names: &str) -> ImportOptions {
+        self.string(AI_CONFIG_PP_OG_EXCLUDE_LIST, names)
+    }
+
+    /// `AI_CONFIG_PP_SLM_TRIANGLE_LIMIT` for `SplitLargeMeshes`.
+    pub fn split_triangle_limit(self, limit: u32) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_SLM_TRIANGLE_LIMIT, clamped(limit))
+    }
+
+    /// `AI_CONFIG_PP_SLM_VERTEX_LIMIT` for `SplitLargeMeshes`.
+    pub fn split_vertex_limit(self, limit: u32) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_SLM_VERTEX_LIMIT, clamped(limit))
+    }
+
+    /// `AI_CONFIG_PP_LBW_MAX_WEIGHTS` for `LimitBoneWeights`.
+    pub fn max_bone_weights(self, weights: u32) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_LBW_MAX_WEIGHTS, clamped(weights))
+    }
+
+    /// `AI_CONFIG_PP_DB_THRESHOLD` for `Debone`.
+    pub fn debone_threshold(self, threshold: f32) -> ImportOptions {
+        self.float(AI_CONFIG_PP_DB_THRESHOLD, threshold)
+    }
+
+    /// `AI_CONFIG_PP_DB_ALL_OR_NONE`, only debones if every bone qualifies.
+    pub fn debone_all_or_none(self, all_or_none: bool) -> ImportOptions {
+        self.bool(AI_CONFIG_PP_DB_ALL_OR_NONE, all_or_none)
+    }
+
+    /// `AI_CONFIG_PP_ICL_PTCACHE_SIZE`, in vertices, for `ImproveCacheLocality`.
+    pub fn vertex_cache_size(self, size: u32) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_ICL_PTCACHE_SIZE, clamped(size))
+    }
+
+    /// `AI_CONFIG_PP_RVC_FLAGS`, the `aiComponent_*` flags removed by `RemoveComponent`.
+    pub fn remove_components(self, components: c_uint) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_RVC_FLAGS, clamped(components))
+    }
+
+    /// `AI_CONFIG_PP_SBP_REMOVE`, the `aiPrimitiveType_*` flags removed by `SortByPType`.
+    pub fn remove_primitive_types(self, types: c_uint) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_SBP_REMOVE, clamped(types))
+    }
+
+    /// `AI_CONFIG_PP_FID_ANIM_ACCURACY`, the epsilon of `FindInvalidData` for animation keys.
+    pub fn animation_accuracy(self, epsilon: f32) -> ImportOptions {
+        self.float(AI_CONFIG_PP_FID_ANIM_ACCURACY, epsilon)
+    }
+
+    /// `AI_CONFIG_PP_TUV_EVALUATE`, the `AI_UVTRAFO_*` flags applied by `TransformUVCoords`.
+    pub fn uv_transformations(self, transformations: c_uint) -> ImportOptions {
+        self.integer(AI_CONFIG_PP_TUV_EVALUATE, clamped(transformations))
+    }
+
+    /// Creates a property store holding the settings.
+    pub fn to_property_store(&self) -> Result<PropertyStore, Error> {
+        let mut store = PropertyStore::new()?;
+        for (key, value) in &self.properties {
+            match value {
+                PropertyValue::Integer(v) => store.set_integer(key, *v)?,
+                PropertyValue::Float(v) => store.set_float(key, *v)?,
+                PropertyValue::String(v) => store.set_string(key, v)?,
+                PropertyValue::Matrix(v) => store.set_matrix(key, v)?,
+            }
+        }
+        Ok(store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn setters_replace_earlier_values_in_order() {
+        let options = ImportOptions::new()
+            .global_scale_factor(2.0)
+            .keep_nodes("a b")
+            .max_bone_weights(4)
+            .global_scale_factor(3.0);
+        assert_eq!(
+            options.properties(),
+            &[
+                (
+                    AI_CONFIG_PP_OG_EXCLUDE_LIST.to_string(),
+                    PropertyValue::String("a b".to_string())
+                ),
+                (
+                    AI_CONFIG_PP_LBW_MAX_WEIGHTS.to_string(),
+                    PropertyValue::Integer(4)
+                ),
+                (
+                    AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY.to_string(),
+                    PropertyValue::Float(3.0)
+                ),
+            ][..]
+        );
+    }
+
+    #[test]
+    fn unsigned_values_are_clamped() {
+        let options = ImportOptions::new()
+            .split_vertex_limit(u32::MAX)
+            .remove_components(aiComponent_NORMALS);
+        assert_eq!(options.properties()[0].1, PropertyValue::Integer(i32::MAX));
+        assert_eq!(options.properties()[1].1, PropertyValue::Integer(2));
+    }
+}
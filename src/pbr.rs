@@ -0,0 +1,192 @@
+use std::os::raw::c_uint;
+
+use data::*;
+use material::{aiGetMaterialFloatArray, Material, MaterialTexture};
+
+/// How the alpha channel of the base color is interpreted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AlphaMode {
+    /// Alpha is ignored, the material is fully opaque.
+    Opaque,
+    /// Fragments with alpha below `PbrMaterial::alpha_cutoff` are discarded.
+    Mask,
+    /// Alpha is used for blending.
+    Blend,
+}
+
+/// A metallic-roughness material, independent of the source file format.
+///
+/// Built from the generic PBR keys or the glTF keys of older assimp versions.
+/// If neither is present the base color and roughness are approximated from
+/// the Phong properties and the material is treated as a dielectric, with a
+/// metallic factor of 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PbrMaterial {
+    pub name: String,
+    /// Linear RGBA base color factor.
+    pub base_color: [f32; 4],
+    pub base_color_texture: Option<MaterialTexture>,
+    pub metallic: f32,
+    pub roughness: f32,
+    /// Metalness in the blue and roughness in the green channel, as in glTF.
+    pub metallic_roughness_texture: Option<MaterialTexture>,
+    /// Linear RGB emissive factor, including the emissive intensity.
+    pub emissive: [f32; 3],
+    pub emissive_texture: Option<MaterialTexture>,
+    pub normal_texture: Option<MaterialTexture>,
+    pub normal_scale: f32,
+    pub occlusion_texture: Option<MaterialTexture>,
+    pub occlusion_strength: f32,
+    pub alpha_mode: AlphaMode,
+    pub alpha_cutoff: f32,
+    pub double_sided: bool,
+}
+
+impl Default for PbrMaterial {
+    fn default() -> PbrMaterial {
+        PbrMaterial {
+            name: String::new(),
+            base_color: [1.0; 4],
+            base_color_texture: None,
+            metallic: 0.0,
+            roughness: 1.0,
+            metallic_roughness_texture: None,
+            emissive: [0.0; 3],
+            emissive_texture: None,
+            normal_texture: None,
+            normal_scale: 1.0,
+            occlusion_texture: None,
+            occlusion_strength: 1.0,
+            alpha_mode: AlphaMode::Opaque,
+            alpha_cutoff: 0.5,
+            double_sided: false,
+        }
+    }
+}
+
+impl PbrMaterial {
+    /// Extracts the PBR parameters of an assimp material.
+    pub fn from_material(mat: &Material) -> PbrMaterial {
+        let mut pbr = PbrMaterial {
+            name: mat.name().unwrap_or_default(),
+            double_sided: mat.two_sided(),
+            ..PbrMaterial::default()
+        };
+
+        let opacity = mat.opacity().unwrap_or(1.0);
+        let base_color = mat
+            .get_color(AI_MATKEY_BASE_COLOR)
+            .or_else(|| mat.get_color(AI_MATKEY_GLTF_PBRMETALLICROUGHNESS_BASE_COLOR_FACTOR))
+            .or_else(|| {
+                mat.diffuse_color().map(|c| aiColor4D {
+                    a: c.a * opacity,
+                    ..c
+                })
+            });
+        if let Some(c) = base_color {
+            pbr.base_color = [c.r, c.g, c.b, c.a];
+        }
+
+        let metallic = mat
+            .get_float(AI_MATKEY_METALLIC_FACTOR)
+            .or_else(|| mat.get_float(AI_MATKEY_GLTF_PBRMETALLICROUGHNESS_METALLIC_FACTOR));
+        let roughness = mat
+            .get_float(AI_MATKEY_ROUGHNESS_FACTOR)
+            .or_else(|| mat.get_float(AI_MATKEY_GLTF_PBRMETALLICROUGHNESS_ROUGHNESS_FACTOR));
+        pbr.metallic = metallic.unwrap_or(0.0);
+        pbr.roughness = match roughness {
+            Some(roughness) => roughness,
+            None => mat.shininess().map_or(1.0, shininess_to_roughness),
+        };
+
+        if let Some(c) = mat.emissive_color() {
+            let intensity = mat.get_float(AI_MATKEY_EMISSIVE_INTENSITY).unwrap_or(1.0);
+            pbr.emissive = [c.r * intensity, c.g * intensity, c.b * intensity];
+        }
+
+        pbr.base_color_texture =
+            first_texture(mat, &[aiTextureType::BaseColor, aiTextureType::Diffuse]);
+        pbr.metallic_roughness_texture = first_texture(
+            mat,
+            &[
+                aiTextureType::Metalness,
+                aiTextureType::DiffuseRoughness,
+                aiTextureType::Unknown,
+            ],
+        );
+        pbr.emissive_texture = first_texture(
+            mat,
+            &[aiTextureType::EmissionColor, aiTextureType::Emissive],
+        );
+        pbr.normal_texture = first_texture(
+            mat,
+            &[
+                aiTextureType::Normals,
+                aiTextureType::NormalCamera,
+                aiTextureType::Height,
+            ],
+        );
+        pbr.occlusion_texture = first_texture(
+            mat,
+            &[aiTextureType::AmbientOcclusion, aiTextureType::Lightmap],
+        );
+
+        if let Some(scale) = texture_float(
+            mat,
+            AI_MATKEY_GLTF_TEXTURE_SCALE_BASE,
+            aiTextureType::Normals,
+        ) {
+            pbr.normal_scale = scale;
+        }
+        if let Some(strength) = texture_float(
+            mat,
+            AI_MATKEY_GLTF_TEXTURE_STRENGTH_BASE,
+            aiTextureType::Lightmap,
+        )
+        .or_else(|| {
+            texture_float(
+                mat,
+                AI_MATKEY_GLTF_TEXTURE_STRENGTH_BASE,
+                aiTextureType::AmbientOcclusion,
+            )
+        }) {
+            pbr.occlusion_strength = strength;
+        }
+
+        if let Some(cutoff) = mat.get_float(AI_MATKEY_GLTF_ALPHACUTOFF) {
+            pbr.alpha_cutoff = cutoff;
+        }
+        pbr.alpha_mode = match mat.get_string(AI_MATKEY_GLTF_ALPHAMODE).as_deref() {
+            Some("MASK") => AlphaMode::Mask,
+            Some("BLEND") => AlphaMode::Blend,
+            Some(_) => AlphaMode::Opaque,
+            None if pbr.base_color[3] < 1.0 => AlphaMode::Blend,
+            None => AlphaMode::Opaque,
+        };
+
+        pbr
+    }
+}
+
+impl<'a> From<Material<'a>> for PbrMaterial {
+    fn from(mat: Material<'a>) -> PbrMaterial {
+        PbrMaterial::from_material(&mat)
+    }
+}
+
+/// Converts a Phong exponent to a perceptual roughness with a similar highlight.
+///
+/// The Blinn-Phong to Beckmann mapping gives `alpha = sqrt(2 / (n + 2))`, the
+/// glTF roughness is its square root as `alpha = roughness^2`.
+fn shininess_to_roughness(shininess: f32) -> f32 {
+    (2.0 / (shininess.max(0.0) + 2.0)).sqrt().sqrt()
+}
+
+fn first_texture(mat: &Material, types: &[aiTextureType]) -> Option<MaterialTexture> {
+    types.iter().filter_map(|&t| mat.texture(t, 0)).next()
+}
+
+fn texture_float(mat: &Material, key: &str, type_: aiTextureType) -> Option<f32> {
+    let mut value = [0.0];
+    aiGetMaterialFloatArray(mat.raw(), key, c_uint::from(type_), 0, &mut value).map(|_| value[0])
+}
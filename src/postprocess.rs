@@ -286,7 +286,10 @@ impl aiPostProcessSteps {
      * If you have multiple armatures on your models we strongly recommend enabling this
      * Instead of writing your own multi-root, multi-armature lookups we have done the
      * hard work for you :)
+     *
+     * Added together with aiBone::mArmature and aiBone::mNode in assimp 5.1.
      */
+    #[cfg(assimp_5_1)]
     pub const PopulateArmatureData: aiPostProcessSteps = aiPostProcessSteps { value: 0x4000 };
 
     // -------------------------------------------------------------------------
@@ -693,6 +696,7 @@ impl Debug for aiPostProcessSteps {
                 first = false;
                 write!(f, "FixInfacingNormals")?;
             }
+            #[cfg(assimp_5_1)]
             if self.set(Self::PopulateArmatureData) {
                 if !first {
                     write!(f, " | ")?;
@@ -0,0 +1,86 @@
+#![allow(non_snake_case)]
+
+//! Declarations of the Assimp C API.
+//!
+//! Without the `dlopen` feature the functions are resolved by the linker,
+//! with it they are looked up at runtime by `Library::open` and every call
+//! goes through the loaded function table.
+
+use data::*;
+use error::Error;
+use std::os::raw::{c_char, c_int, c_uint};
+
+/// Fails with `Error::LibraryNotLoaded` while the functions cannot be called yet.
+#[cfg(feature = "dlopen")]
+pub fn check_loaded() -> Result<(), Error> {
+    if ::library::is_loaded() {
+        Ok(())
+    } else {
+        Err(Error::LibraryNotLoaded)
+    }
+}
+
+/// Fails with `Error::LibraryNotLoaded` while the functions cannot be called yet.
+#[cfg(not(feature = "dlopen"))]
+pub fn check_loaded() -> Result<(), Error> {
+    Ok(())
+}
+
+macro_rules! assimp_api {
+    ($(pub fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)*) => {
+        #[cfg(not(feature = "dlopen"))]
+        extern "C" {
+            $(pub fn $name($($arg: $ty),*) $(-> $ret)?;)*
+        }
+
+        /// Function table resolved from a dynamically loaded library.
+        #[cfg(feature = "dlopen")]
+        pub struct Api {
+            $(pub $name: unsafe extern "C" fn($($ty),*) $(-> $ret)?,)*
+        }
+
+        #[cfg(feature = "dlopen")]
+        impl Api {
+            /// Resolves every function of the API from `lib`.
+            pub unsafe fn load(lib: &::libloading::Library) -> Result<Api, ::libloading::Error> {
+                Ok(Api {
+                    $($name: *lib.get(concat!(stringify!($name), "\0").as_bytes())?,)*
+                })
+            }
+        }
+
+        $(
+            #[cfg(feature = "dlopen")]
+            #[allow(clippy::too_many_arguments)]
+            pub unsafe fn $name($($arg: $ty),*) $(-> $ret)? {
+                (::library::api().$name)($($arg),*)
+            }
+        )*
+    };
+}
+
+assimp_api! {
+    pub fn aiImportFile(pFile: *const c_char, pFlags: c_uint) -> *const aiScene;
+    pub fn aiImportFileEx(pFile: *const c_char, pFlags: c_uint, pFS: *mut aiFileIO) -> *const aiScene;
+    pub fn aiImportFileExWithProperties(pFile: *const c_char, pFlags: c_uint, pFS: *mut aiFileIO, pProps: *const aiPropertyStore) -> *const aiScene;
+    pub fn aiImportFileFromMemory(pBuffer: *const c_char, pLength: c_uint, pFlags: c_uint, pHint: *const c_char) -> *const aiScene;
+    pub fn aiImportFileFromMemoryWithProperties(pBuffer: *const c_char, pLength: c_uint, pFlags: c_uint, pHint: *const c_char, pProps: *const aiPropertyStore) -> *const aiScene;
+    pub fn aiApplyPostProcessing(pScene: *const aiScene, pFlags: c_uint) -> *const aiScene;
+    pub fn aiReleaseImport(pScene: *const aiScene);
+    pub fn aiCreatePropertyStore() -> *mut aiPropertyStore;
+    pub fn aiReleasePropertyStore(p: *mut aiPropertyStore);
+    pub fn aiSetImportPropertyInteger(store: *mut aiPropertyStore, szName: *const c_char, value: c_int);
+    pub fn aiSetImportPropertyFloat(store: *mut aiPropertyStore, szName: *const c_char, value: f32);
+    pub fn aiSetImportPropertyString(store: *mut aiPropertyStore, szName: *const c_char, st: *const aiString);
+    pub fn aiSetImportPropertyMatrix(store: *mut aiPropertyStore, szName: *const c_char, mat: *const aiMatrix4x4);
+    pub fn aiGetErrorString() -> *const c_char;
+    pub fn aiAttachLogStream(stream: *const aiLogStream);
+
+    pub fn aiGetMaterialProperty(pMat: *const aiMaterial, pKey: *const c_char, type_: c_uint, index: c_uint, pPropOut: *mut *const aiMaterialProperty) -> c_int;
+    pub fn aiGetMaterialFloatArray(pMat: *const aiMaterial, pKey: *const c_char, type_: c_uint, index: c_uint, pOut: *mut f32, pMax: *mut c_uint) -> c_int;
+    pub fn aiGetMaterialIntegerArray(pMat: *const aiMaterial, pKey: *const c_char, type_: c_uint, index: c_uint, pOut: *mut c_int, pMax: *mut c_uint) -> c_int;
+    pub fn aiGetMaterialColor(pMat: *const aiMaterial, pKey: *const c_char, type_: c_uint, index: c_uint, pOut: *mut aiColor4D) -> c_int;
+    pub fn aiGetMaterialString(pMat: *const aiMaterial, pKey: *const c_char, type_: c_uint, index: c_uint, pOut: *mut aiString) -> c_int;
+    pub fn aiGetMaterialTextureCount(pMat: *const aiMaterial, type_: c_uint) -> c_uint;
+    pub fn aiGetMaterialTexture(mat: *const aiMaterial, type_: c_uint, index: c_uint, path: *mut aiString, mapping: *mut c_uint, uvindex: *mut c_uint, blend: *mut f32, op: *mut c_uint, mapmode: *mut c_uint, flags: *mut c_uint) -> c_int;
+}
@@ -0,0 +1,471 @@
+use std::collections::HashMap;
+
+use animation::{Animation, NodeTrack};
+use data::*;
+use scene::Scene;
+
+/// A local transformation split into translation, rotation and scaling.
+#[derive(Copy, Clone)]
+pub struct Transform {
+    pub translation: aiVector3D,
+    pub rotation: aiQuaternion,
+    pub scaling: aiVector3D,
+}
+
+impl Transform {
+    /// Decomposes an affine transformation matrix.
+    pub fn from_matrix(m: &aiMatrix4x4) -> Transform {
+        let (translation, rotation, scaling) = m.decompose();
+        Transform {
+            translation,
+            rotation,
+            scaling,
+        }
+    }
+
+    /// The matrix applying scaling, rotation and translation, in this order.
+    pub fn to_matrix(&self) -> aiMatrix4x4 {
+        aiMatrix4x4::from_trs(self.translation, self.rotation, self.scaling)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform {
+            translation: aiVector3D::new(0.0, 0.0, 0.0),
+            rotation: aiQuaternion::identity(),
+            scaling: aiVector3D::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+/// The transformations of every node of a scene at one point in time.
+///
+/// Both vectors are indexed by `Sampler::node_index`.
+#[derive(Clone)]
+pub struct Pose {
+    /// Transformations relative to the parent node.
+    pub local: Vec<Transform>,
+    /// Transformations relative to the root of the hierarchy.
+    pub global: Vec<aiMatrix4x4>,
+}
+
+impl Pose {
+    /// Computes the global transformations of a pose from its local ones.
+    ///
+    /// `parents` holds the parent index of every node, see `Sampler::parents`,
+    /// parents must come before their children.
+    pub fn from_local(local: Vec<Transform>, parents: &[Option<usize>]) -> Pose {
+        let mut global: Vec<aiMatrix4x4> = Vec::with_capacity(local.len());
+        for (transform, parent) in local.iter().zip(parents.iter()) {
+            let m = transform.to_matrix();
+            let m = match *parent {
+                Some(parent) => global[parent] * m,
+                None => m,
+            };
+            global.push(m);
+        }
+        Pose { local, global }
+    }
+}
+
+/// Evaluates an animation clip on the node hierarchy of a scene.
+///
+/// Nodes are indexed in depth-first order, so parents always come before
+/// their children. Nodes without a track keep their own transformation.
+pub struct Sampler<'a> {
+    animation: Animation<'a>,
+    names: Vec<String>,
+    parents: Vec<Option<usize>>,
+    rest: Vec<Transform>,
+    tracks: Vec<Option<NodeTrack<'a>>>,
+    indices: HashMap<String, usize>,
+}
+
+impl<'a> Sampler<'a> {
+    pub fn new(scene: &'a Scene, animation: Animation<'a>) -> Sampler<'a> {
+        let tracks: HashMap<String, NodeTrack<'a>> = animation
+            .channels()
+            .map(|track| (track.node_name().into_owned(), track))
+            .collect();
+
+        let mut sampler = Sampler {
+            animation,
+            names: Vec::new(),
+            parents: Vec::new(),
+            rest: Vec::new(),
+            tracks: Vec::new(),
+            indices: HashMap::new(),
+        };
+        let mut node_indices: HashMap<*const aiNode, usize> = HashMap::new();
+
+        if let Some(root) = scene.root() {
+            for (node, _) in root.depth_first() {
+                let index = sampler.names.len();
+                let name = node.name().into_owned();
+                let parent = node
+                    .parent()
+                    .and_then(|parent| node_indices.get(&(parent.raw() as *const aiNode)))
+                    .cloned();
+
+                node_indices.insert(node.raw() as *const aiNode, index);
+                sampler.indices.entry(name.clone()).or_insert(index);
+                sampler.tracks.push(tracks.get(&name).cloned());
+                sampler.parents.push(parent);
+                sampler
+                    .rest
+                    .push(Transform::from_matrix(&node.transformation()));
+                sampler.names.push(name);
+            }
+        }
+        sampler
+    }
+
+    pub fn animation(&self) -> Animation<'a> {
+        self.animation
+    }
+
+    /// Index of the first node with the given name in the poses.
+    pub fn node_index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).cloned()
+    }
+
+    /// Names of the nodes, in pose order.
+    pub fn node_names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Parent indices of the nodes, `None` for the root.
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.parents
+    }
+
+    /// The pose of the scene without the animation applied.
+    pub fn rest_pose(&self) -> Pose {
+        Pose::from_local(self.rest.clone(), &self.parents)
+    }
+
+    /// Evaluates the clip at `seconds` from its start.
+    ///
+    /// Times outside of a track's keys are handled by its pre and post
+    /// states, take the time modulo `Animation::duration_seconds` to loop
+    /// the whole clip.
+    pub fn sample(&self, seconds: f64) -> Pose {
+        let ticks = seconds * self.animation.ticks_per_second();
+        let local = self
+            .rest
+            .iter()
+            .zip(self.tracks.iter())
+            .map(|(rest, track)| match track {
+                Some(track) => sample_track(track, ticks, rest),
+                None => *rest,
+            })
+            .collect();
+        Pose::from_local(local, &self.parents)
+    }
+}
+
+/// Evaluates a node track at `ticks`, components without a value are
+/// taken from `rest`.
+pub fn sample_track(track: &NodeTrack, ticks: f64, rest: &Transform) -> Transform {
+    let (pre, post) = (track.pre_state(), track.post_state());
+    Transform {
+        translation: sample_vector(track.position_keys(), ticks, pre, post)
+            .unwrap_or(rest.translation),
+        rotation: sample_rotation(track.rotation_keys(), ticks, pre, post).unwrap_or(rest.rotation),
+        scaling: sample_vector(track.scaling_keys(), ticks, pre, post).unwrap_or(rest.scaling),
+    }
+}
+
+/// Linearly interpolates vector keys at `ticks`.
+///
+/// `pre` and `post` decide the value before the first and after the last
+/// key, `None` is returned if there are no keys or the state is `Default`.
+pub fn sample_vector(
+    keys: &[aiVectorKey],
+    ticks: f64,
+    pre: aiAnimBehaviour,
+    post: aiAnimBehaviour,
+) -> Option<aiVector3D> {
+    sample_keys(keys, ticks, pre, post)
+}
+
+/// Spherically interpolates rotation keys at `ticks`, see `sample_vector`.
+pub fn sample_rotation(
+    keys: &[aiQuatKey],
+    ticks: f64,
+    pre: aiAnimBehaviour,
+    post: aiAnimBehaviour,
+) -> Option<aiQuaternion> {
+    sample_keys(keys, ticks, pre, post)
+}
+
+trait Keyframe: Copy {
+    type Value: Copy;
+
+    fn time(&self) -> f64;
+    fn value(&self) -> Self::Value;
+    fn interpolate(a: Self::Value, b: Self::Value, t: f32) -> Self::Value;
+
+    /// Whether the key holds its value until the next one.
+    fn is_step(&self) -> bool;
+}
+
+impl Keyframe for aiVectorKey {
+    type Value = aiVector3D;
+
+    fn time(&self) -> f64 {
+        self.mTime
+    }
+
+    fn value(&self) -> aiVector3D {
+        self.mValue
+    }
+
+    fn interpolate(a: aiVector3D, b: aiVector3D, t: f32) -> aiVector3D {
+        aiVector3D::new(
+            a.x + (b.x - a.x) * t,
+            a.y + (b.y - a.y) * t,
+            a.z + (b.z - a.z) * t,
+        )
+    }
+
+    #[cfg(assimp_5_3)]
+    fn is_step(&self) -> bool {
+        self.mInterpolation == aiAnimInterpolation::Step.into()
+    }
+
+    #[cfg(not(assimp_5_3))]
+    fn is_step(&self) -> bool {
+        false
+    }
+}
+
+impl Keyframe for aiQuatKey {
+    type Value = aiQuaternion;
+
+    fn time(&self) -> f64 {
+        self.mTime
+    }
+
+    fn value(&self) -> aiQuaternion {
+        self.mValue
+    }
+
+    fn interpolate(a: aiQuaternion, b: aiQuaternion, t: f32) -> aiQuaternion {
+        a.slerp(b, t)
+    }
+
+    #[cfg(assimp_5_3)]
+    fn is_step(&self) -> bool {
+        self.mInterpolation == aiAnimInterpolation::Step.into()
+    }
+
+    #[cfg(not(assimp_5_3))]
+    fn is_step(&self) -> bool {
+        false
+    }
+}
+
+fn sample_keys<K: Keyframe>(
+    keys: &[K],
+    ticks: f64,
+    pre: aiAnimBehaviour,
+    post: aiAnimBehaviour,
+) -> Option<K::Value> {
+    let (first, last) = (keys.first()?, keys.last()?);
+    let (start, end) = (first.time(), last.time());
+
+    let out_of_range = if ticks < start {
+        Some((pre, first))
+    } else if ticks > end {
+        Some((post, last))
+    } else {
+        None
+    };
+    let ticks = match out_of_range {
+        None => ticks,
+        Some((aiAnimBehaviour::Default, _)) => return None,
+        Some((aiAnimBehaviour::Constant, key)) => return Some(key.value()),
+        Some((aiAnimBehaviour::Linear, _)) => ticks,
+        Some((aiAnimBehaviour::Repeat, _)) if end > start => {
+            start + (ticks - start).rem_euclid(end - start)
+        }
+        Some((aiAnimBehaviour::Repeat, _)) => start,
+    };
+
+    if keys.len() == 1 {
+        return Some(first.value());
+    }
+
+    // The segment containing `ticks`, the outer ones extrapolate.
+    let next = keys
+        .partition_point(|key| key.time() <= ticks)
+        .clamp(1, keys.len() - 1);
+    let (a, b) = (keys[next - 1], keys[next]);
+
+    let span = b.time() - a.time();
+    let t = if span > 0.0 {
+        (ticks - a.time()) / span
+    } else {
+        0.0
+    };
+
+    if a.is_step() {
+        return Some(if t < 1.0 { a.value() } else { b.value() });
+    }
+    Some(K::interpolate(a.value(), b.value(), t as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+    use test_util::assert_close;
+
+    fn vector_key(time: f64, x: f32, y: f32, z: f32) -> aiVectorKey {
+        aiVectorKey {
+            mTime: time,
+            mValue: aiVector3D::new(x, y, z),
+            #[cfg(assimp_5_3)]
+            mInterpolation: aiAnimInterpolation::Linear.into(),
+        }
+    }
+
+    fn quat_key(time: f64, rotation: aiQuaternion) -> aiQuatKey {
+        aiQuatKey {
+            mTime: time,
+            mValue: rotation,
+            #[cfg(assimp_5_3)]
+            mInterpolation: aiAnimInterpolation::Spherical_Linear.into(),
+        }
+    }
+
+    fn rotation_z(angle: f32) -> aiQuaternion {
+        aiQuaternion::new((angle / 2.0).cos(), 0.0, 0.0, (angle / 2.0).sin())
+    }
+
+    fn sample_x(keys: &[aiVectorKey], ticks: f64, behaviour: aiAnimBehaviour) -> Option<f32> {
+        sample_vector(keys, ticks, behaviour, behaviour).map(|v| v.x)
+    }
+
+    #[test]
+    fn interpolates_linearly_between_keys() {
+        let keys = [
+            vector_key(0.0, 0.0, 0.0, 0.0),
+            vector_key(10.0, 10.0, 20.0, -5.0),
+            vector_key(20.0, 0.0, 0.0, 0.0),
+        ];
+        let (pre, post) = (aiAnimBehaviour::Default, aiAnimBehaviour::Default);
+
+        assert_close(
+            sample_vector(&keys, 2.5, pre, post).unwrap(),
+            2.5,
+            5.0,
+            -1.25,
+        );
+        assert_close(
+            sample_vector(&keys, 10.0, pre, post).unwrap(),
+            10.0,
+            20.0,
+            -5.0,
+        );
+        assert_close(
+            sample_vector(&keys, 15.0, pre, post).unwrap(),
+            5.0,
+            10.0,
+            -2.5,
+        );
+        assert!(sample_vector(&[], 1.0, pre, post).is_none());
+    }
+
+    #[test]
+    fn slerps_along_the_shortest_path() {
+        // the same rotation as a quarter turn, from the opposite hemisphere
+        let q = rotation_z(FRAC_PI_2);
+        let keys = [
+            quat_key(0.0, aiQuaternion::identity()),
+            quat_key(1.0, aiQuaternion::new(-q.w, -q.x, -q.y, -q.z)),
+        ];
+        let behaviour = aiAnimBehaviour::Default;
+
+        let halfway = sample_rotation(&keys, 0.5, behaviour, behaviour).unwrap();
+        let expected = rotation_z(FRAC_PI_2 / 2.0);
+        assert!((halfway.dot(expected).abs() - 1.0).abs() < 1e-5);
+        assert!((halfway.dot(halfway) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn default_state_has_no_value_outside_the_keys() {
+        let keys = [
+            vector_key(1.0, 1.0, 0.0, 0.0),
+            vector_key(3.0, 3.0, 0.0, 0.0),
+        ];
+        assert_eq!(sample_x(&keys, 0.0, aiAnimBehaviour::Default), None);
+        assert_eq!(sample_x(&keys, 4.0, aiAnimBehaviour::Default), None);
+        assert_eq!(sample_x(&keys, 2.0, aiAnimBehaviour::Default), Some(2.0));
+    }
+
+    #[test]
+    fn constant_state_holds_the_nearest_key() {
+        let keys = [
+            vector_key(1.0, 1.0, 0.0, 0.0),
+            vector_key(3.0, 3.0, 0.0, 0.0),
+        ];
+        assert_eq!(sample_x(&keys, 0.0, aiAnimBehaviour::Constant), Some(1.0));
+        assert_eq!(sample_x(&keys, 5.0, aiAnimBehaviour::Constant), Some(3.0));
+    }
+
+    #[test]
+    fn linear_state_extrapolates_the_outer_keys() {
+        let keys = [
+            vector_key(1.0, 1.0, 0.0, 0.0),
+            vector_key(3.0, 3.0, 0.0, 0.0),
+        ];
+        assert_eq!(sample_x(&keys, 0.0, aiAnimBehaviour::Linear), Some(0.0));
+        assert_eq!(sample_x(&keys, 5.0, aiAnimBehaviour::Linear), Some(5.0));
+    }
+
+    #[test]
+    fn repeat_state_wraps_around_the_keys() {
+        let keys = [
+            vector_key(1.0, 1.0, 0.0, 0.0),
+            vector_key(3.0, 3.0, 0.0, 0.0),
+        ];
+        assert_eq!(sample_x(&keys, 0.0, aiAnimBehaviour::Repeat), Some(2.0));
+        assert_eq!(sample_x(&keys, 3.5, aiAnimBehaviour::Repeat), Some(1.5));
+        assert_eq!(sample_x(&keys, 6.0, aiAnimBehaviour::Repeat), Some(2.0));
+    }
+
+    #[cfg(assimp_5_3)]
+    #[test]
+    fn step_keys_hold_their_value() {
+        let mut keys = [
+            vector_key(0.0, 0.0, 0.0, 0.0),
+            vector_key(2.0, 2.0, 0.0, 0.0),
+        ];
+        keys[0].mInterpolation = aiAnimInterpolation::Step.into();
+        assert_eq!(sample_x(&keys, 1.5, aiAnimBehaviour::Default), Some(0.0));
+        assert_eq!(sample_x(&keys, 2.0, aiAnimBehaviour::Default), Some(2.0));
+    }
+
+    #[test]
+    fn pose_chains_global_transforms() {
+        let root = Transform {
+            translation: aiVector3D::new(1.0, 0.0, 0.0),
+            rotation: rotation_z(FRAC_PI_2),
+            scaling: aiVector3D::new(1.0, 1.0, 1.0),
+        };
+        let child = Transform {
+            translation: aiVector3D::new(2.0, 0.0, 0.0),
+            ..Transform::default()
+        };
+        let pose = Pose::from_local(vec![root, child], &[None, Some(0)]);
+
+        let origin = aiVector3D::new(0.0, 0.0, 0.0);
+        assert_close(pose.global[0].transform_point(origin), 1.0, 0.0, 0.0);
+        assert_close(pose.global[1].transform_point(origin), 1.0, 2.0, 0.0);
+        let x = aiVector3D::new(1.0, 0.0, 0.0);
+        assert_close(pose.global[1].transform_point(x), 1.0, 3.0, 0.0);
+    }
+}
@@ -0,0 +1,270 @@
+use std::os::raw::c_uint;
+
+use animation::Animation;
+use camera::Camera;
+use cimport::{
+    aiApplyPostProcessing, aiImportFile, aiImportFileEx, aiImportFileExWithProperties,
+    aiImportFileFromMemory, aiReleaseImport, merge_meshes,
+};
+use data::*;
+use error::Error;
+use filesystem::FileSystem;
+use glui::tools::mesh::Mesh;
+use light::Light;
+use material::Material;
+use mesh::MeshView;
+use metadata::Metadata;
+use node::Node;
+use options::ImportOptions;
+use postprocess::aiPostProcessSteps;
+use texture::Texture;
+use util::raw_ptr_slice;
+
+/// An imported scene, owned by the caller.
+///
+/// Wraps the pointer returned by the import functions and releases it with
+/// `aiReleaseImport` when dropped. All accessors borrow from the scene, so
+/// the data cannot outlive the import.
+pub struct Scene {
+    ptr: *const aiScene,
+}
+
+unsafe impl Send for Scene {}
+unsafe impl Sync for Scene {}
+
+impl Scene {
+    /// Imports the given file, see `aiImportFile`.
+    pub fn import(file: &str, flags: aiPostProcessSteps) -> Result<Scene, Error> {
+        aiImportFile(file, flags).map(Scene::from_import)
+    }
+
+    /// Imports a file with the importer and post-processing settings of `options`.
+    pub fn import_with_options(
+        file: &str,
+        flags: aiPostProcessSteps,
+        options: &ImportOptions,
+    ) -> Result<Scene, Error> {
+        let store = options.to_property_store()?;
+        aiImportFileExWithProperties(file, flags, None, store.raw()).map(Scene::from_import)
+    }
+
+    /// Imports a file and the files it references from `fs`.
+    pub fn import_from_file_system(
+        fs: &dyn FileSystem,
+        file: &str,
+        flags: aiPostProcessSteps,
+    ) -> Result<Scene, Error> {
+        aiImportFileEx(file, flags, fs).map(Scene::from_import)
+    }
+
+    /// Imports a file from a memory buffer.
+    ///
+    /// `format_hint` is the file extension of the data without the dot, e.g. `"obj"`,
+    /// or empty to let assimp detect the format. Files referencing other files,
+    /// like OBJ material libraries, are imported without those.
+    pub fn import_from_memory(
+        bytes: &[u8],
+        format_hint: &str,
+        flags: aiPostProcessSteps,
+    ) -> Result<Scene, Error> {
+        aiImportFileFromMemory(bytes, flags, format_hint).map(Scene::from_import)
+    }
+
+    /// Runs further post-processing steps on the imported scene.
+    ///
+    /// Useful for choosing steps after inspecting the scene, e.g.
+    /// `CalcTangentSpace` only for materials with normal maps. If a step
+    /// fails the scene is released by assimp and `Error::PostProcessing`
+    /// is returned.
+    pub fn apply_post_processing(self, steps: aiPostProcessSteps) -> Result<Scene, Error> {
+        unsafe { aiApplyPostProcessing(self.into_raw(), steps) }.map(Scene::from_import)
+    }
+
+    /// Takes ownership of a scene returned by one of the import functions.
+    ///
+    /// Returns `None` for a null pointer.
+    ///
+    /// # Safety
+    /// `ptr` must be null or a scene returned by assimp that is not
+    /// released by anyone else.
+    pub unsafe fn from_raw(ptr: *const aiScene) -> Option<Scene> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Scene { ptr })
+        }
+    }
+
+    /// Takes ownership of the result of a successful import, which is never null.
+    pub(crate) fn from_import(ptr: *const aiScene) -> Scene {
+        Scene { ptr }
+    }
+
+    /// Gives up ownership, the caller becomes responsible for calling `aiReleaseImport`.
+    pub fn into_raw(self) -> *const aiScene {
+        let ptr = self.ptr;
+        std::mem::forget(self);
+        ptr
+    }
+
+    /// The underlying assimp pointer, valid as long as the scene lives.
+    pub fn as_ptr(&self) -> *const aiScene {
+        self.ptr
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &aiScene {
+        unsafe { &*self.ptr }
+    }
+
+    /// Any combination of the AI_SCENE_FLAGS_XXX flags.
+    pub fn flags(&self) -> c_uint {
+        self.raw().mFlags
+    }
+
+    /// Whether the `AI_SCENE_FLAGS_INCOMPLETE` flag is set.
+    ///
+    /// Assimp sets it for files without meshes, e.g. animation clips or
+    /// skeletons, which are still valid scenes.
+    pub fn is_incomplete(&self) -> bool {
+        self.flags() & AI_SCENE_FLAGS_INCOMPLETE != 0
+    }
+
+    /// The root node of the hierarchy.
+    pub fn root_node(&self) -> Option<&aiNode> {
+        unsafe { self.raw().mRootNode.as_ref() }
+    }
+
+    /// The root of the node hierarchy.
+    pub fn root(&self) -> Option<Node<'_>> {
+        self.root_node().map(Node::new)
+    }
+
+    /// Finds the first node with the given name, depth-first.
+    pub fn find_node(&self, name: &str) -> Option<Node<'_>> {
+        self.root()?.find(name)
+    }
+
+    /// The meshes of the scene, indexed by the nodes' mesh indices.
+    pub fn meshes(&self) -> &[&aiMesh] {
+        let scene = self.raw();
+        unsafe { raw_ptr_slice(scene.mMeshes, scene.mNumMeshes) }
+    }
+
+    /// Safe views of the meshes, in the order of `meshes()`.
+    pub fn mesh_views<'a>(&'a self) -> impl Iterator<Item = MeshView<'a>> + 'a {
+        self.meshes().iter().map(|&mesh| MeshView::new(mesh))
+    }
+
+    /// Merges the triangles of every mesh referenced by a node into one glui
+    /// mesh, placed in world space by the node hierarchy.
+    ///
+    /// Meshes without a node are skipped, meshes referenced by several nodes
+    /// are added once per node. Missing uvs are filled with zeros, the normals
+    /// are `None` unless every mesh has normals.
+    pub fn to_world_mesh(&self) -> Mesh {
+        let mut meshes = Vec::new();
+        if let Some(root) = self.root() {
+            for (node, world) in root.depth_first() {
+                for &index in node.mesh_indices() {
+                    meshes.push((MeshView::new(self.meshes()[index as usize]), Some(world)));
+                }
+            }
+        }
+        merge_meshes(meshes)
+    }
+
+    /// The materials of the scene, indexed by `aiMesh::mMaterialIndex`.
+    pub fn materials(&self) -> &[&aiMaterial] {
+        let scene = self.raw();
+        unsafe { raw_ptr_slice(scene.mMaterials, scene.mNumMaterials) }
+    }
+
+    /// Safe views of the materials, in the order of `materials()`.
+    pub fn material_views<'a>(&'a self) -> impl Iterator<Item = Material<'a>> + 'a {
+        self.materials().iter().map(|&mat| Material::new(mat))
+    }
+
+    /// The animations of the scene.
+    pub fn animations(&self) -> &[&aiAnimation] {
+        let scene = self.raw();
+        unsafe { raw_ptr_slice(scene.mAnimations, scene.mNumAnimations) }
+    }
+
+    /// Safe views of the animations, in the order of `animations()`.
+    pub fn animation_views<'a>(&'a self) -> impl Iterator<Item = Animation<'a>> + 'a {
+        self.animations().iter().map(|&anim| Animation::new(anim))
+    }
+
+    /// Finds the first animation with the given name.
+    pub fn find_animation(&self, name: &str) -> Option<Animation<'_>> {
+        self.animation_views()
+            .find(|anim| anim.raw().mName.as_bytes() == name.as_bytes())
+    }
+
+    /// The textures embedded into the file.
+    pub fn textures(&self) -> &[&aiTexture] {
+        let scene = self.raw();
+        unsafe { raw_ptr_slice(scene.mTextures, scene.mNumTextures) }
+    }
+
+    /// Safe views of the embedded textures, in the order of `textures()`.
+    pub fn texture_views<'a>(&'a self) -> impl Iterator<Item = Texture<'a>> + 'a {
+        self.textures().iter().map(|&tex| Texture::new(tex))
+    }
+
+    /// Finds the embedded texture referenced by a material texture path,
+    /// either as `*N` or by the texture's original file name.
+    pub fn embedded_texture(&self, path: &str) -> Option<Texture<'_>> {
+        if let Some(index) = path.strip_prefix('*') {
+            let index: usize = index.parse().ok()?;
+            return self.textures().get(index).map(|&tex| Texture::new(tex));
+        }
+        let name = |p: &str| p.rsplit(['/', '\\']).next().map(str::to_owned);
+        self.texture_views().find(|tex| {
+            let filename = tex.filename();
+            !filename.is_empty() && name(&filename) == name(path)
+        })
+    }
+
+    /// The light sources of the scene.
+    pub fn lights(&self) -> &[&aiLight] {
+        let scene = self.raw();
+        unsafe { raw_ptr_slice(scene.mLights, scene.mNumLights) }
+    }
+
+    /// The lights of the scene resolved into world space, undefined lights are skipped.
+    pub fn world_lights(&self) -> Vec<Light> {
+        self.lights()
+            .iter()
+            .filter_map(|&light| Light::from_scene(light, self))
+            .collect()
+    }
+
+    /// The cameras of the scene, the first one is the default view.
+    pub fn cameras(&self) -> &[&aiCamera] {
+        let scene = self.raw();
+        unsafe { raw_ptr_slice(scene.mCameras, scene.mNumCameras) }
+    }
+
+    /// Safe views of the cameras, in the order of `cameras()`.
+    pub fn camera_views<'a>(&'a self) -> impl Iterator<Item = Camera<'a>> + 'a {
+        self.cameras().iter().map(|&cam| Camera::new(cam))
+    }
+
+    /// The global metadata of the scene.
+    pub fn metadata(&self) -> Option<&aiMetadata> {
+        unsafe { self.raw().mMetaData.as_ref() }
+    }
+
+    /// Safe view of the global metadata, e.g. unit scale and up axis.
+    pub fn metadata_view(&self) -> Option<Metadata<'_>> {
+        self.metadata().map(Metadata::new)
+    }
+}
+
+impl Drop for Scene {
+    fn drop(&mut self) {
+        aiReleaseImport(self.ptr);
+    }
+}
@@ -0,0 +1,256 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use bone::Bone;
+use data::*;
+use error::Error;
+use mesh::MeshView;
+use node::Node;
+use sampler::{Pose, Sampler};
+use scene::Scene;
+
+/// A joint of a `Skeleton`.
+#[derive(Clone, Debug)]
+pub struct Joint {
+    /// The name of the joint's bone and node.
+    pub name: String,
+    /// Index of the parent joint, `None` for root joints.
+    pub parent: Option<usize>,
+    /// Bind pose transformation relative to the parent joint, or to the
+    /// scene's root for root joints.
+    pub bind_transform: aiMatrix4x4,
+    /// Transforms from mesh space to the joint's space in bind pose.
+    pub inverse_bind: aiMatrix4x4,
+}
+
+/// The joints of one or more skinned meshes, ordered parent-first.
+///
+/// The parent of a joint always has a lower index than the joint itself,
+/// so global transformations can be computed in a single pass.
+#[derive(Clone, Debug)]
+pub struct Skeleton {
+    joints: Vec<Joint>,
+    indices: HashMap<String, usize>,
+}
+
+impl Skeleton {
+    /// A skeleton of joints ordered parent-first, joints sharing a name are
+    /// looked up as the first one.
+    pub(crate) fn from_joints(joints: Vec<Joint>) -> Skeleton {
+        let mut indices = HashMap::new();
+        for (index, joint) in joints.iter().enumerate() {
+            indices.entry(joint.name.clone()).or_insert(index);
+        }
+        Skeleton { joints, indices }
+    }
+
+    /// Starts building a skeleton from the bones of `scene`'s meshes.
+    pub fn builder(scene: &Scene) -> SkeletonBuilder<'_> {
+        SkeletonBuilder::new(scene)
+    }
+
+    pub fn joints(&self) -> &[Joint] {
+        &self.joints
+    }
+
+    pub fn len(&self) -> usize {
+        self.joints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.joints.is_empty()
+    }
+
+    /// Index of the joint with the given name.
+    pub fn joint_index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).cloned()
+    }
+
+    /// Inverts the bones' weight lists of `mesh` into `N` influences per vertex.
+    ///
+    /// Only the `N` strongest influences of a vertex are kept and renormalized to
+    /// sum up to one, vertices with fewer influences are padded with joint 0 and
+    /// weight 0. Fails if a bone of the mesh is not a joint of the skeleton or
+    /// if its index does not fit into a `u16`.
+    pub fn skin_weights<const N: usize>(&self, mesh: MeshView) -> Result<SkinWeights<N>, Error> {
+        let mut influences: Vec<Vec<(u16, f32)>> = vec![Vec::new(); mesh.num_vertices()];
+        for bone in mesh.bones() {
+            let joint = self
+                .joint_index(&bone.name())
+                .ok_or_else(|| Error::MissingJoint(bone.name().into_owned()))?;
+            let joint = u16::try_from(joint).map_err(|_| Error::TooManyJoints(self.len()))?;
+            for weight in bone.weights() {
+                if weight.mWeight <= 0.0 {
+                    continue;
+                }
+                if let Some(list) = influences.get_mut(weight.mVertexId as usize) {
+                    list.push((joint, weight.mWeight));
+                }
+            }
+        }
+
+        let mut skin = SkinWeights {
+            joints: Vec::with_capacity(influences.len()),
+            weights: Vec::with_capacity(influences.len()),
+            truncated: Vec::new(),
+        };
+        for (vertex, mut list) in influences.into_iter().enumerate() {
+            list.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+            if list.len() > N {
+                skin.truncated.push(vertex);
+                list.truncate(N);
+            }
+
+            let sum: f32 = list.iter().map(|&(_, w)| w).sum();
+            let mut joints = [0u16; N];
+            let mut weights = [0.0f32; N];
+            for (i, &(joint, weight)) in list.iter().enumerate() {
+                joints[i] = joint;
+                weights[i] = weight / sum;
+            }
+            skin.joints.push(joints);
+            skin.weights.push(weights);
+        }
+        Ok(skin)
+    }
+
+    /// The skinning matrices of the joints for a pose of `sampler`, mapping
+    /// from mesh space in bind pose to the animated world space.
+    ///
+    /// Joints without a node in the pose keep their bind pose.
+    pub fn joint_matrices(&self, sampler: &Sampler, pose: &Pose) -> Vec<aiMatrix4x4> {
+        let bind_pose = self.bind_pose();
+        self.joints
+            .iter()
+            .zip(bind_pose.iter())
+            .map(|(joint, &bind)| {
+                let global = sampler
+                    .node_index(&joint.name)
+                    .and_then(|index| pose.global.get(index).cloned())
+                    .unwrap_or(bind);
+                global * joint.inverse_bind
+            })
+            .collect()
+    }
+
+    /// Global transformations of the joints in bind pose.
+    pub fn bind_pose(&self) -> Vec<aiMatrix4x4> {
+        let mut globals: Vec<aiMatrix4x4> = Vec::with_capacity(self.joints.len());
+        for joint in &self.joints {
+            let global = match joint.parent {
+                Some(parent) => globals[parent] * joint.bind_transform,
+                None => joint.bind_transform,
+            };
+            globals.push(global);
+        }
+        globals
+    }
+}
+
+/// Per-vertex joint indices and weights of a mesh, see `Skeleton::skin_weights`.
+///
+/// Joint indices refer to `Skeleton::joints`, so skeletons are limited to
+/// 65536 joints.
+#[derive(Clone, Debug)]
+pub struct SkinWeights<const N: usize = 4> {
+    pub joints: Vec<[u16; N]>,
+    pub weights: Vec<[f32; N]>,
+    /// Vertices influenced by more than `N` joints, their weakest influences
+    /// were dropped.
+    pub truncated: Vec<usize>,
+}
+
+/// Collects bones from meshes and resolves them to the scene's nodes,
+/// see `Skeleton::builder`.
+pub struct SkeletonBuilder<'a> {
+    scene: &'a Scene,
+    bones: Vec<Bone<'a>>,
+}
+
+impl<'a> SkeletonBuilder<'a> {
+    pub fn new(scene: &'a Scene) -> SkeletonBuilder<'a> {
+        SkeletonBuilder {
+            scene,
+            bones: Vec::new(),
+        }
+    }
+
+    /// Adds the bones of `mesh`.
+    pub fn mesh(mut self, mesh: MeshView<'a>) -> SkeletonBuilder<'a> {
+        self.bones.extend(mesh.bones());
+        self
+    }
+
+    /// Adds the bones of every mesh in the scene.
+    pub fn all_meshes(mut self) -> SkeletonBuilder<'a> {
+        let scene = self.scene;
+        for mesh in scene.mesh_views() {
+            self.bones.extend(mesh.bones());
+        }
+        self
+    }
+
+    /// Maps the bones to nodes and orders them parent-first.
+    ///
+    /// Bones shared by several meshes become a single joint, the offset
+    /// matrix of the first one is used. Fails if a bone has no node.
+    pub fn build(&self) -> Result<Skeleton, Error> {
+        let mut inverse_binds: HashMap<*const aiNode, aiMatrix4x4> = HashMap::new();
+        for bone in &self.bones {
+            let node = match bone.node() {
+                Some(node) => node,
+                None => self
+                    .scene
+                    .find_node(&bone.name())
+                    .ok_or_else(|| Error::MissingNode(bone.name().into_owned()))?,
+            };
+            inverse_binds
+                .entry(node.raw() as *const aiNode)
+                .or_insert_with(|| bone.offset_matrix());
+        }
+
+        let mut joints = Vec::with_capacity(inverse_binds.len());
+        let mut node_indices: HashMap<*const aiNode, usize> = HashMap::new();
+
+        if let Some(root) = self.scene.root() {
+            for (node, _) in root.depth_first() {
+                let key = node.raw() as *const aiNode;
+                let inverse_bind = match inverse_binds.get(&key) {
+                    Some(&m) => m,
+                    None => continue,
+                };
+                let (parent, bind_transform) = Self::parent_joint(node, &node_indices);
+                let index = joints.len();
+
+                node_indices.insert(key, index);
+                joints.push(Joint {
+                    name: node.name().into_owned(),
+                    parent,
+                    bind_transform,
+                    inverse_bind,
+                });
+            }
+        }
+
+        Ok(Skeleton::from_joints(joints))
+    }
+
+    /// Finds the closest ancestor that is already a joint and the
+    /// transformation relative to it.
+    fn parent_joint(
+        node: Node,
+        node_indices: &HashMap<*const aiNode, usize>,
+    ) -> (Option<usize>, aiMatrix4x4) {
+        let mut transform = node.transformation();
+        let mut current = node.parent();
+        while let Some(ancestor) = current {
+            if let Some(&index) = node_indices.get(&(ancestor.raw() as *const aiNode)) {
+                return (Some(index), transform);
+            }
+            transform = ancestor.transformation() * transform;
+            current = ancestor.parent();
+        }
+        (None, transform)
+    }
+}
@@ -0,0 +1,243 @@
+use glui::tools::mesh::{Mesh, MeshFace};
+use glui::tools::Vec2;
+
+use data::*;
+use error::Error;
+use mesh::MeshView;
+use skeleton::Skeleton;
+use util::normalize;
+
+/// Vertex positions and normals of a mesh deformed by its bones, see `skin_mesh`.
+#[derive(Clone)]
+pub struct SkinnedVertices {
+    pub positions: Vec<aiVector3D>,
+    /// Empty if the mesh has no normals.
+    pub normals: Vec<aiVector3D>,
+}
+
+impl SkinnedVertices {
+    /// The minimum and maximum corners of the positions, `None` if there are none.
+    pub fn bounds(&self) -> Option<(aiVector3D, aiVector3D)> {
+        let first = *self.positions.first()?;
+        Some(self.positions.iter().fold((first, first), |(min, max), p| {
+            (
+                aiVector3D::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                aiVector3D::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z)),
+            )
+        }))
+    }
+
+    /// Builds a glui `Mesh` with the deformed vertices and the UVs and
+    /// triangles of `mesh`, without normals if the mesh has none.
+    pub fn to_mesh(&self, mesh: MeshView) -> Mesh {
+        let uvcoords = match mesh.uv_channel(0) {
+            Some(uvs) => uvs.iter().map(|uv| uv.xy()).collect(),
+            None => (0..mesh.num_vertices())
+                .map(|_| Vec2::new(0.0, 0.0))
+                .collect(),
+        };
+        let faces = mesh
+            .faces()
+            .filter(|face| face.len() == 3)
+            .map(|face| MeshFace::new(face[0], face[1], face[2]))
+            .collect();
+
+        Mesh {
+            points: self.positions.clone(),
+            normals: if self.normals.is_empty() {
+                None
+            } else {
+                Some(self.normals.clone())
+            },
+            faces,
+            uvcoords: Some(uvcoords),
+        }
+    }
+}
+
+/// Deforms the positions and normals of `mesh` on the CPU.
+///
+/// `joint_matrices` are indexed like `skeleton.joints()`, usually from
+/// `Skeleton::joint_matrices`. Each vertex is the weighted sum of its bones'
+/// transformations, vertices without weights are left unchanged. Fails if a
+/// bone of the mesh is not a joint of the skeleton.
+pub fn skin_mesh(
+    mesh: MeshView,
+    skeleton: &Skeleton,
+    joint_matrices: &[aiMatrix4x4],
+) -> Result<SkinnedVertices, Error> {
+    let zero = aiVector3D::new(0.0, 0.0, 0.0);
+    let source_positions = mesh.positions();
+    let source_normals = mesh.normals();
+    let mut positions = vec![zero; source_positions.len()];
+    let mut normals = vec![zero; source_normals.len()];
+    let mut totals = vec![0.0f32; source_positions.len()];
+
+    for bone in mesh.bones() {
+        let joint = skeleton
+            .joint_index(&bone.name())
+            .ok_or_else(|| Error::MissingJoint(bone.name().into_owned()))?;
+        let matrix = joint_matrices
+            .get(joint)
+            .cloned()
+            .unwrap_or_else(aiMatrix4x4::identity);
+        let normal_matrix = matrix.normal_matrix();
+
+        for weight in bone.weights() {
+            let vertex = weight.mVertexId as usize;
+            let w = weight.mWeight;
+            if vertex >= positions.len() || w <= 0.0 {
+                continue;
+            }
+
+            let p = matrix.transform_point(source_positions[vertex]);
+            positions[vertex] = add_scaled(positions[vertex], p, w);
+            if let Some(&n) = source_normals.get(vertex) {
+                let n = normal_matrix.transform_vector(n);
+                normals[vertex] = add_scaled(normals[vertex], n, w);
+            }
+            totals[vertex] += w;
+        }
+    }
+
+    for (vertex, &total) in totals.iter().enumerate() {
+        if total > 0.0 {
+            let p = positions[vertex];
+            positions[vertex] = aiVector3D::new(p.x / total, p.y / total, p.z / total);
+        } else {
+            positions[vertex] = source_positions[vertex];
+        }
+
+        if let Some(n) = normals.get_mut(vertex) {
+            *n = if total > 0.0 {
+                normalize(*n)
+            } else {
+                source_normals[vertex]
+            };
+        }
+    }
+
+    Ok(SkinnedVertices { positions, normals })
+}
+
+fn add_scaled(a: aiVector3D, b: aiVector3D, s: f32) -> aiVector3D {
+    aiVector3D::new(a.x + b.x * s, a.y + b.y * s, a.z + b.z * s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skeleton::Joint;
+    use std::f32::consts::FRAC_PI_2;
+    use std::os::raw::c_uint;
+    #[cfg(assimp_5_1)]
+    use std::ptr;
+    use test_util::{self, assert_close};
+
+    fn bone(name: &str, weights: &[aiVertexWeight]) -> aiBone {
+        aiBone {
+            mName: aiString::from(name),
+            mNumWeights: weights.len() as c_uint,
+            #[cfg(assimp_5_1)]
+            mArmature: ptr::null(),
+            #[cfg(assimp_5_1)]
+            mNode: ptr::null(),
+            mWeights: weights.as_ptr(),
+            mOffsetMatrix: aiMatrix4x4::identity(),
+        }
+    }
+
+    fn weight(vertex: c_uint, weight: f32) -> aiVertexWeight {
+        aiVertexWeight {
+            mVertexId: vertex,
+            mWeight: weight,
+        }
+    }
+
+    fn mesh(positions: &[aiVector3D], normals: &[aiVector3D], bones: &[*const aiBone]) -> aiMesh {
+        let mut mesh = test_util::mesh(positions, normals);
+        mesh.mNumBones = bones.len() as c_uint;
+        mesh.mBones = bones.as_ptr();
+        mesh
+    }
+
+    fn skeleton(names: &[&str]) -> Skeleton {
+        Skeleton::from_joints(
+            names
+                .iter()
+                .map(|name| Joint {
+                    name: name.to_string(),
+                    parent: None,
+                    bind_transform: aiMatrix4x4::identity(),
+                    inverse_bind: aiMatrix4x4::identity(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn single_joint_moves_positions_and_rotates_normals() {
+        let positions = [
+            aiVector3D::new(1.0, 0.0, 0.0),
+            aiVector3D::new(0.0, 1.0, 0.0),
+        ];
+        let normals = positions;
+        let weights = [weight(0, 1.0), weight(1, 1.0)];
+        let root = bone("root", &weights);
+        let bones = [&root as *const aiBone];
+        let mesh = mesh(&positions, &normals, &bones);
+
+        let quarter_turn =
+            aiQuaternion::new((FRAC_PI_2 / 2.0).cos(), 0.0, 0.0, (FRAC_PI_2 / 2.0).sin());
+        let matrix = aiMatrix4x4::from_trs(
+            aiVector3D::new(0.0, 0.0, 5.0),
+            quarter_turn,
+            aiVector3D::new(1.0, 1.0, 1.0),
+        );
+        let skinned = skin_mesh(MeshView::new(&mesh), &skeleton(&["root"]), &[matrix]).unwrap();
+
+        assert_close(skinned.positions[0], 0.0, 1.0, 5.0);
+        assert_close(skinned.positions[1], -1.0, 0.0, 5.0);
+        assert_close(skinned.normals[0], 0.0, 1.0, 0.0);
+        assert_close(skinned.normals[1], -1.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn mixed_weights_blend_the_joint_transforms() {
+        let positions = [
+            aiVector3D::new(1.0, 0.0, 0.0),
+            aiVector3D::new(0.0, 1.0, 0.0),
+        ];
+        let a_weights = [weight(0, 0.5)];
+        let b_weights = [weight(0, 0.5)];
+        let (a, b) = (bone("a", &a_weights), bone("b", &b_weights));
+        let bones = [&a as *const aiBone, &b as *const aiBone];
+        let mesh = mesh(&positions, &[], &bones);
+
+        let shift = aiMatrix4x4::from_trs(
+            aiVector3D::new(2.0, 0.0, 0.0),
+            aiQuaternion::identity(),
+            aiVector3D::new(1.0, 1.0, 1.0),
+        );
+        let matrices = [aiMatrix4x4::identity(), shift];
+        let skinned = skin_mesh(MeshView::new(&mesh), &skeleton(&["a", "b"]), &matrices).unwrap();
+
+        assert_close(skinned.positions[0], 2.0, 0.0, 0.0);
+        // vertices without weights are left unchanged
+        assert_close(skinned.positions[1], 0.0, 1.0, 0.0);
+        assert!(skinned.normals.is_empty());
+        assert!(skinned.to_mesh(MeshView::new(&mesh)).normals.is_none());
+    }
+
+    #[test]
+    fn unknown_bones_are_an_error() {
+        let positions = [aiVector3D::new(1.0, 0.0, 0.0)];
+        let weights = [weight(0, 1.0)];
+        let arm = bone("arm", &weights);
+        let bones = [&arm as *const aiBone];
+        let mesh = mesh(&positions, &[], &bones);
+
+        let result = skin_mesh(MeshView::new(&mesh), &skeleton(&["root"]), &[]);
+        assert_eq!(result.err(), Some(Error::MissingJoint("arm".to_string())));
+    }
+}
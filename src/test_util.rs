@@ -0,0 +1,32 @@
+//! Fixtures shared by the unit tests.
+
+use std::mem;
+use std::os::raw::c_uint;
+
+use data::*;
+
+/// Asserts that the components of `v` are within 1e-5 of `x`, `y` and `z`.
+pub fn assert_close(v: aiVector3D, x: f32, y: f32, z: f32) {
+    assert!(
+        (v.x - x).abs() < 1e-5 && (v.y - y).abs() < 1e-5 && (v.z - z).abs() < 1e-5,
+        "({}, {}, {}) != ({}, {}, {})",
+        v.x,
+        v.y,
+        v.z,
+        x,
+        y,
+        z
+    );
+}
+
+/// A mesh of the given vertices, `normals` may be empty. The slices must
+/// outlive the mesh.
+pub fn mesh(positions: &[aiVector3D], normals: &[aiVector3D]) -> aiMesh {
+    let mut mesh: aiMesh = unsafe { mem::zeroed() };
+    mesh.mNumVertices = positions.len() as c_uint;
+    mesh.mVertices = positions.as_ptr();
+    if !normals.is_empty() {
+        mesh.mNormals = normals.as_ptr();
+    }
+    mesh
+}
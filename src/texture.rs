@@ -0,0 +1,241 @@
+use std::borrow::Cow;
+use std::ffi::CStr;
+#[cfg(feature = "decode")]
+use std::fmt::{Display, Formatter};
+use std::slice;
+
+#[cfg(feature = "decode")]
+use jpeg_decoder;
+#[cfg(feature = "decode")]
+use png;
+
+use data::*;
+use util::raw_slice;
+
+/// The contents of an embedded texture.
+#[derive(Copy, Clone, Debug)]
+pub enum TextureData<'a> {
+    /// Uncompressed ARGB8888 texels, row by row.
+    Texels {
+        width: u32,
+        height: u32,
+        texels: &'a [aiTexel],
+    },
+    /// The bytes of an image file, `format_hint` is its lower-case file
+    /// extension (e.g. `png` or `jpg`) or empty if unknown.
+    Compressed {
+        format_hint: &'a str,
+        data: &'a [u8],
+    },
+}
+
+/// An image with 8 bit RGBA pixels, row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+/// A borrowed, safe view of an embedded `aiTexture`.
+#[derive(Copy, Clone)]
+pub struct Texture<'a> {
+    tex: &'a aiTexture,
+}
+
+impl<'a> Texture<'a> {
+    pub fn new(tex: &'a aiTexture) -> Texture<'a> {
+        Texture { tex }
+    }
+
+    /// The underlying assimp structure.
+    pub fn raw(&self) -> &'a aiTexture {
+        self.tex
+    }
+
+    /// Whether the texture is stored as an image file instead of texels.
+    pub fn is_compressed(&self) -> bool {
+        self.tex.mHeight == 0
+    }
+
+    /// The format hint of the texture, see `aiTexture::achFormatHint`.
+    pub fn format_hint(&self) -> &'a str {
+        let hint = unsafe {
+            slice::from_raw_parts(
+                self.tex.achFormatHint.as_ptr() as *const u8,
+                HINTMAXTEXTURELEN,
+            )
+        };
+        let hint = match CStr::from_bytes_until_nul(hint) {
+            Ok(hint) => hint.to_bytes(),
+            Err(_) => hint,
+        };
+        std::str::from_utf8(hint).unwrap_or("")
+    }
+
+    /// The original file name of the texture, might be empty.
+    pub fn filename(&self) -> Cow<'a, str> {
+        self.tex.mFilename.to_string_lossy()
+    }
+
+    pub fn data(&self) -> TextureData<'a> {
+        if self.is_compressed() {
+            TextureData::Compressed {
+                format_hint: self.format_hint(),
+                data: unsafe { raw_slice(self.tex.pcData as *const u8, self.tex.mWidth) },
+            }
+        } else {
+            let len = self.tex.mWidth as usize * self.tex.mHeight as usize;
+            TextureData::Texels {
+                width: self.tex.mWidth,
+                height: self.tex.mHeight,
+                texels: if self.tex.pcData.is_null() {
+                    &[]
+                } else {
+                    unsafe { slice::from_raw_parts(self.tex.pcData, len) }
+                },
+            }
+        }
+    }
+
+    /// Converts an uncompressed texture to RGBA, `None` for compressed ones.
+    pub fn texels_to_rgba(&self) -> Option<RgbaImage> {
+        match self.data() {
+            TextureData::Texels {
+                width,
+                height,
+                texels,
+            } => Some(RgbaImage {
+                width,
+                height,
+                pixels: texels.iter().flat_map(|t| [t.r, t.g, t.b, t.a]).collect(),
+            }),
+            TextureData::Compressed { .. } => None,
+        }
+    }
+
+    /// Decodes the texture to RGBA, compressed textures must be PNG or JPEG files.
+    ///
+    /// Only available with the `decode` feature.
+    #[cfg(feature = "decode")]
+    pub fn decode(&self) -> Result<RgbaImage, DecodeError> {
+        match self.data() {
+            TextureData::Compressed { format_hint, data } => decode_image(format_hint, data),
+            TextureData::Texels { .. } => Ok(self.texels_to_rgba().unwrap()),
+        }
+    }
+}
+
+impl<'a> From<&'a aiTexture> for Texture<'a> {
+    fn from(tex: &'a aiTexture) -> Texture<'a> {
+        Texture::new(tex)
+    }
+}
+
+/// Error returned by `Texture::decode`.
+#[cfg(feature = "decode")]
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The image is neither a PNG nor a JPEG file, holds the format hint.
+    UnsupportedFormat(String),
+    Png(png::DecodingError),
+    Jpeg(jpeg_decoder::Error),
+}
+
+#[cfg(feature = "decode")]
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            DecodeError::UnsupportedFormat(hint) => {
+                write!(f, "unsupported embedded texture format '{}'", hint)
+            }
+            DecodeError::Png(e) => write!(f, "failed to decode png: {}", e),
+            DecodeError::Jpeg(e) => write!(f, "failed to decode jpeg: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "decode")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "decode")]
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+#[cfg(feature = "decode")]
+const JPEG_SIGNATURE: &[u8] = b"\xff\xd8";
+
+/// Decodes a PNG or JPEG file, detected by its signature or else its format hint.
+#[cfg(feature = "decode")]
+fn decode_image(format_hint: &str, data: &[u8]) -> Result<RgbaImage, DecodeError> {
+    if data.starts_with(PNG_SIGNATURE) || format_hint == "png" {
+        decode_png(data).map_err(DecodeError::Png)
+    } else if data.starts_with(JPEG_SIGNATURE) || format_hint == "jpg" {
+        decode_jpeg(data).map_err(DecodeError::Jpeg)
+    } else {
+        Err(DecodeError::UnsupportedFormat(format_hint.to_owned()))
+    }
+}
+
+#[cfg(feature = "decode")]
+fn decode_png(data: &[u8]) -> Result<RgbaImage, png::DecodingError> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf)?;
+    buf.truncate(info.buffer_size());
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf
+            .chunks(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buf
+            .chunks(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale | png::ColorType::Indexed => {
+            buf.iter().flat_map(|&l| [l, l, l, 255]).collect()
+        }
+    };
+    Ok(RgbaImage {
+        width: info.width,
+        height: info.height,
+        pixels,
+    })
+}
+
+#[cfg(feature = "decode")]
+fn decode_jpeg(data: &[u8]) -> Result<RgbaImage, jpeg_decoder::Error> {
+    let mut decoder = jpeg_decoder::Decoder::new(data);
+    let buf = decoder.decode()?;
+    let info = decoder.info().unwrap();
+
+    let pixels = match info.pixel_format {
+        jpeg_decoder::PixelFormat::RGB24 => buf
+            .chunks(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        jpeg_decoder::PixelFormat::L8 => buf.iter().flat_map(|&l| [l, l, l, 255]).collect(),
+        jpeg_decoder::PixelFormat::L16 => buf
+            .chunks(2)
+            .flat_map(|p| {
+                let l = (u16::from_ne_bytes([p[0], p[1]]) >> 8) as u8;
+                [l, l, l, 255]
+            })
+            .collect(),
+        jpeg_decoder::PixelFormat::CMYK32 => buf
+            .chunks(4)
+            .flat_map(|p| {
+                let k = 255 - p[3] as u32;
+                let channel = |c: u8| ((255 - c as u32) * k / 255) as u8;
+                [channel(p[0]), channel(p[1]), channel(p[2]), 255]
+            })
+            .collect(),
+    };
+    Ok(RgbaImage {
+        width: info.width as u32,
+        height: info.height as u32,
+        pixels,
+    })
+}
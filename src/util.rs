@@ -0,0 +1,38 @@
+use std::os::raw::c_uint;
+use std::slice;
+
+use data::aiVector3D;
+
+/// Views an assimp `(pointer, count)` pair as a slice, a null pointer gives an empty slice.
+pub(crate) unsafe fn raw_slice<'a, T>(ptr: *const T, len: c_uint) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+/// Views an assimp array of pointers as a slice of references.
+///
+/// Assimp never stores null pointers in its object arrays, so the
+/// elements can be reinterpreted as references.
+pub(crate) unsafe fn raw_ptr_slice<'a, T>(ptr: *const *const T, len: c_uint) -> &'a [&'a T] {
+    raw_slice(ptr as *const &T, len)
+}
+
+pub(crate) fn normalize(v: aiVector3D) -> aiVector3D {
+    let len = v.dot(v).sqrt();
+    if len > 0.0 {
+        aiVector3D::new(v.x / len, v.y / len, v.z / len)
+    } else {
+        v
+    }
+}
+
+pub(crate) fn cross(a: aiVector3D, b: aiVector3D) -> aiVector3D {
+    aiVector3D::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
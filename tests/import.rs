@@ -0,0 +1,160 @@
+extern crate assimp;
+#[cfg(feature = "archive")]
+extern crate zip;
+
+use std::collections::HashMap;
+#[cfg(feature = "archive")]
+use std::io::{Cursor, Write};
+
+use assimp::*;
+
+const CUBE: &[u8] = include_bytes!("../cube.obj");
+
+#[cfg(feature = "dlopen")]
+fn load() {
+    Library::open_default().expect("assimp must be installed to run the tests");
+}
+
+#[cfg(not(feature = "dlopen"))]
+fn load() {}
+
+#[test]
+fn imports_obj_from_memory() {
+    load();
+    let scene = Scene::import_from_memory(CUBE, "obj", aiPostProcessSteps::Triangulate).unwrap();
+
+    assert_eq!(scene.meshes().len(), 1);
+    let mesh = scene.mesh_views().next().unwrap();
+    assert_eq!(mesh.num_vertices(), 24);
+    assert_eq!(mesh.num_faces(), 12);
+    assert!(mesh.faces().all(|face| face.len() == 3));
+}
+
+#[test]
+fn rejects_unknown_formats_and_garbage() {
+    load();
+    let garbage = [0xffu8; 64];
+    let steps = aiPostProcessSteps::Triangulate;
+
+    assert!(Scene::import_from_memory(&garbage, "nosuchformat", steps).is_err());
+    assert!(Scene::import_from_memory(&[], "obj", steps).is_err());
+}
+
+fn cube_files() -> HashMap<String, Vec<u8>> {
+    let mut files = HashMap::new();
+    files.insert("cube.obj".to_string(), CUBE.to_vec());
+    files.insert(
+        "cube.mtl".to_string(),
+        b"newmtl Material\nKd 1 0 0\n".to_vec(),
+    );
+    files
+}
+
+#[test]
+fn resolves_materials_through_the_file_system() {
+    load();
+    let files = cube_files();
+    let scene = Scene::import_from_file_system(&files, "cube.obj", aiPostProcessSteps::Triangulate)
+        .unwrap();
+
+    let mesh = scene.mesh_views().next().unwrap();
+    let material = Material::new(scene.materials()[mesh.material_index()]);
+    assert_eq!(material.name().as_deref(), Some("Material"));
+    let diffuse = material.diffuse_color().unwrap();
+    assert_eq!((diffuse.r, diffuse.g, diffuse.b), (1.0, 0.0, 0.0));
+}
+
+#[cfg(feature = "archive")]
+#[test]
+fn imports_through_zip_archives() {
+    load();
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in cube_files() {
+        writer
+            .start_file(format!("models/{}", name), Default::default())
+            .unwrap();
+        writer.write_all(&data).unwrap();
+    }
+    let archive = writer.finish().unwrap().into_inner();
+
+    let zip = ZipFileSystem::new(Cursor::new(archive)).unwrap();
+    let scene =
+        Scene::import_from_file_system(&zip, "models/cube.obj", aiPostProcessSteps::Triangulate)
+            .unwrap();
+    let mesh = scene.mesh_views().next().unwrap();
+    assert_eq!(mesh.num_faces(), 12);
+    let material = Material::new(scene.materials()[mesh.material_index()]);
+    let diffuse = material.diffuse_color().unwrap();
+    assert_eq!((diffuse.r, diffuse.g, diffuse.b), (1.0, 0.0, 0.0));
+}
+
+#[test]
+fn missing_files_of_the_file_system_are_an_error() {
+    load();
+    let files = cube_files();
+    let steps = aiPostProcessSteps::Triangulate;
+    assert!(Scene::import_from_file_system(&files, "missing.obj", steps).is_err());
+}
+
+#[test]
+fn import_options_reach_the_importer() {
+    load();
+    let options = ImportOptions::new().global_scale_factor(2.0);
+    options.to_property_store().unwrap();
+    let dir = env!("CARGO_MANIFEST_DIR");
+    let file = format!("{}/cube.obj", dir);
+    let steps = aiPostProcessSteps::GlobalScale;
+
+    let plain = Scene::import_with_options(&file, steps, &ImportOptions::new()).unwrap();
+    let scaled = Scene::import_with_options(&file, steps, &options).unwrap();
+    let extent = |scene: &Scene| {
+        let mesh = scene.to_world_mesh();
+        mesh.points.iter().fold(0.0f32, |max, p| max.max(p.x.abs()))
+    };
+    assert!((extent(&plain) - 1.0).abs() < 1e-5);
+    assert!((extent(&scaled) - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn importer_reads_files_and_memory() {
+    load();
+    let file = format!("{}/cube.obj", env!("CARGO_MANIFEST_DIR"));
+    let importer = Importer::new();
+
+    let from_file = importer.read_file(&file).unwrap();
+    let from_memory = importer.read_memory(CUBE, "obj").unwrap();
+    for scene in &[from_file, from_memory] {
+        let mesh = scene.mesh_views().next().unwrap();
+        assert_eq!(mesh.num_faces(), 12);
+        assert_eq!(mesh.normals().len(), mesh.num_vertices());
+    }
+    assert!(importer.read_file("missing.obj").is_err());
+}
+
+#[test]
+fn importer_logs_to_the_sink() {
+    load();
+    let mut messages = Vec::new();
+    {
+        let importer = Importer::new().log_to(|msg| messages.push(msg.to_string()));
+        importer.read_memory(CUBE, "obj").unwrap();
+    }
+    assert!(!messages.is_empty());
+}
+
+#[test]
+fn post_processing_adds_tangents() {
+    load();
+    let scene = Scene::import_from_memory(CUBE, "obj", aiPostProcessSteps::Triangulate).unwrap();
+    assert!(scene.mesh_views().next().unwrap().tangents().is_empty());
+
+    let scene = scene
+        .apply_post_processing(
+            aiPostProcessSteps::CalcTangentSpace | aiPostProcessSteps::ValidateDataStructure,
+        )
+        .unwrap();
+    let mesh = scene.mesh_views().next().unwrap();
+    assert_eq!(mesh.num_faces(), 12);
+    assert_eq!(mesh.tangents().len(), mesh.num_vertices());
+    assert_eq!(mesh.bitangents().len(), mesh.num_vertices());
+}